@@ -0,0 +1,61 @@
+use aiply::{CodeParsingContext, Symbol};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use std::hint::black_box;
+
+const LARGE_RUST: &str = include_str!("inputs/large.rs");
+const LARGE_TS: &str = include_str!("inputs/large.ts");
+
+/// Picks every tenth symbol as important, roughly what a patch touching a
+/// handful of items in a large file looks like.
+fn important_symbols(ctx: &mut CodeParsingContext, source: &str) -> Vec<Symbol> {
+    let symbols = ctx.extract_symbols_with_range(source);
+    ctx.process_symbols(symbols)
+        .into_iter()
+        .step_by(10)
+        .map(|s| s.symbol)
+        .collect()
+}
+
+fn bench_language(c: &mut Criterion, language: &str, source: &str) {
+    let mut ctx = CodeParsingContext::new(language);
+    let mut group = c.benchmark_group(language);
+    group.sample_size(20);
+
+    group.bench_function("extract_symbols_with_range", |b| {
+        b.iter(|| ctx.extract_symbols_with_range(black_box(source)))
+    });
+
+    let symbols = ctx.extract_symbols_with_range(source);
+    group.bench_function("process_symbols", |b| {
+        b.iter_batched(
+            || symbols.clone(),
+            |symbols| ctx.process_symbols(symbols),
+            BatchSize::SmallInput,
+        )
+    });
+
+    let important = important_symbols(&mut ctx, source);
+    group.bench_function("collapse_unrelated_symbols", |b| {
+        b.iter_batched(
+            || important.clone(),
+            |important| ctx.collapse_unrelated_symbols(black_box(source), important),
+            BatchSize::SmallInput,
+        )
+    });
+
+    let collapsed_doc = ctx.collapse_unrelated_symbols(source, important);
+    let collapsed = collapsed_doc.collapsed_document();
+    group.bench_function("uncollapse_document", |b| {
+        b.iter(|| collapsed_doc.uncollapse_document(black_box(&collapsed)))
+    });
+
+    group.finish();
+}
+
+fn bench_collapse(c: &mut Criterion) {
+    bench_language(c, "rust", LARGE_RUST);
+    bench_language(c, "typescript", LARGE_TS);
+}
+
+criterion_group!(benches, bench_collapse);
+criterion_main!(benches);
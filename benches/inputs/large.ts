@@ -0,0 +1,19693 @@
+// Benchmark input: a generated TypeScript module with the shapes of a typical
+// service layer (interfaces, enums, generic classes, namespaces, functions).
+import { EventEmitter } from "node:events";
+import type { Readable } from "node:stream";
+import * as path from "node:path";
+
+export type Id<T extends string> = string & { readonly __brand: T };
+export type Result<T, E = Error> = { ok: true; value: T } | { ok: false; error: E };
+
+export interface User0Record {
+  total: number;
+  createdAt: Date;
+  active: boolean;
+  count: number;
+  tags: string[];
+  updatedAt: Date;
+}
+
+export enum User0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User0Record>();
+  #status: User0Status = User0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User0Record]>): User0Repository<T> {
+    const repository = new User0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser0(records: readonly User0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser0 = async (stream: Readable): Promise<User0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User0Record[];
+};
+
+export interface Order0Record {
+  createdAt: Date;
+  total: number;
+  active: boolean;
+  name: string;
+  tags: string[];
+  id: string;
+}
+
+export enum Order0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order0Record>();
+  #status: Order0Status = Order0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order0Record]>): Order0Repository<T> {
+    const repository = new Order0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder0(records: readonly Order0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder0 = async (stream: Readable): Promise<Order0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order0Record[];
+};
+
+export interface Invoice0Record {
+  id: string;
+  createdAt: Date;
+  owner: string | null;
+  active: boolean;
+  total: number;
+  updatedAt: Date;
+}
+
+export enum Invoice0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice0Record>();
+  #status: Invoice0Status = Invoice0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice0Record]>): Invoice0Repository<T> {
+    const repository = new Invoice0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice0(records: readonly Invoice0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice0 = async (stream: Readable): Promise<Invoice0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice0Record[];
+};
+
+export interface Product0Record {
+  createdAt: Date;
+  owner: string | null;
+  active: boolean;
+  count: number;
+  tags: string[];
+  name: string;
+}
+
+export enum Product0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product0Record>();
+  #status: Product0Status = Product0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product0Record]>): Product0Repository<T> {
+    const repository = new Product0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct0(records: readonly Product0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct0 = async (stream: Readable): Promise<Product0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product0Record[];
+};
+
+export interface Session0Record {
+  name: string;
+  owner: string | null;
+  updatedAt: Date;
+  createdAt: Date;
+  notes: string | undefined;
+  active: boolean;
+}
+
+export enum Session0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session0Record>();
+  #status: Session0Status = Session0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session0Record]>): Session0Repository<T> {
+    const repository = new Session0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession0(records: readonly Session0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession0 = async (stream: Readable): Promise<Session0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session0Record[];
+};
+
+export interface Token0Record {
+  notes: string | undefined;
+  total: number;
+  name: string;
+  count: number;
+  active: boolean;
+  owner: string | null;
+}
+
+export enum Token0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token0Record>();
+  #status: Token0Status = Token0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token0Record]>): Token0Repository<T> {
+    const repository = new Token0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken0(records: readonly Token0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken0 = async (stream: Readable): Promise<Token0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token0Record[];
+};
+
+export interface Account0Record {
+  count: number;
+  notes: string | undefined;
+  name: string;
+  tags: string[];
+  owner: string | null;
+  active: boolean;
+}
+
+export enum Account0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account0Record>();
+  #status: Account0Status = Account0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account0Record]>): Account0Repository<T> {
+    const repository = new Account0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount0(records: readonly Account0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount0 = async (stream: Readable): Promise<Account0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account0Record[];
+};
+
+export interface Cart0Record {
+  total: number;
+  updatedAt: Date;
+  count: number;
+  tags: string[];
+  notes: string | undefined;
+  owner: string | null;
+}
+
+export enum Cart0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart0Record>();
+  #status: Cart0Status = Cart0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart0Record]>): Cart0Repository<T> {
+    const repository = new Cart0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart0(records: readonly Cart0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart0 = async (stream: Readable): Promise<Cart0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart0Record[];
+};
+
+export interface Shipment0Record {
+  owner: string | null;
+  tags: string[];
+  createdAt: Date;
+  active: boolean;
+  updatedAt: Date;
+  notes: string | undefined;
+}
+
+export enum Shipment0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment0Record>();
+  #status: Shipment0Status = Shipment0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment0Record]>): Shipment0Repository<T> {
+    const repository = new Shipment0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment0(records: readonly Shipment0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment0 = async (stream: Readable): Promise<Shipment0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment0Record[];
+};
+
+export interface Payment0Record {
+  count: number;
+  updatedAt: Date;
+  active: boolean;
+  tags: string[];
+  id: string;
+  notes: string | undefined;
+}
+
+export enum Payment0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment0Record>();
+  #status: Payment0Status = Payment0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment0Record]>): Payment0Repository<T> {
+    const repository = new Payment0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment0(records: readonly Payment0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment0 = async (stream: Readable): Promise<Payment0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment0Record[];
+};
+
+export interface Review0Record {
+  tags: string[];
+  updatedAt: Date;
+  notes: string | undefined;
+  active: boolean;
+  owner: string | null;
+  name: string;
+}
+
+export enum Review0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review0Record>();
+  #status: Review0Status = Review0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review0Record]>): Review0Repository<T> {
+    const repository = new Review0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview0(records: readonly Review0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview0 = async (stream: Readable): Promise<Review0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review0Record[];
+};
+
+export interface Category0Record {
+  owner: string | null;
+  count: number;
+  updatedAt: Date;
+  notes: string | undefined;
+  active: boolean;
+  createdAt: Date;
+}
+
+export enum Category0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category0Record>();
+  #status: Category0Status = Category0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category0Record]>): Category0Repository<T> {
+    const repository = new Category0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory0(records: readonly Category0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory0 = async (stream: Readable): Promise<Category0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category0Record[];
+};
+
+export interface Warehouse0Record {
+  count: number;
+  id: string;
+  total: number;
+  name: string;
+  tags: string[];
+  notes: string | undefined;
+}
+
+export enum Warehouse0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse0Record>();
+  #status: Warehouse0Status = Warehouse0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse0Record]>): Warehouse0Repository<T> {
+    const repository = new Warehouse0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse0(records: readonly Warehouse0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse0 = async (stream: Readable): Promise<Warehouse0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse0Record[];
+};
+
+export interface Supplier0Record {
+  updatedAt: Date;
+  tags: string[];
+  notes: string | undefined;
+  owner: string | null;
+  id: string;
+  active: boolean;
+}
+
+export enum Supplier0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier0Record>();
+  #status: Supplier0Status = Supplier0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier0Record]>): Supplier0Repository<T> {
+    const repository = new Supplier0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier0(records: readonly Supplier0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier0 = async (stream: Readable): Promise<Supplier0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier0Record[];
+};
+
+export interface Coupon0Record {
+  id: string;
+  count: number;
+  createdAt: Date;
+  notes: string | undefined;
+  active: boolean;
+  total: number;
+}
+
+export enum Coupon0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon0Record>();
+  #status: Coupon0Status = Coupon0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon0Record]>): Coupon0Repository<T> {
+    const repository = new Coupon0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon0(records: readonly Coupon0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon0 = async (stream: Readable): Promise<Coupon0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon0Record[];
+};
+
+export interface Refund0Record {
+  id: string;
+  notes: string | undefined;
+  total: number;
+  createdAt: Date;
+  tags: string[];
+  name: string;
+}
+
+export enum Refund0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund0Record>();
+  #status: Refund0Status = Refund0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund0Record]>): Refund0Repository<T> {
+    const repository = new Refund0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund0(records: readonly Refund0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund0 = async (stream: Readable): Promise<Refund0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund0Record[];
+};
+
+export interface Ticket0Record {
+  active: boolean;
+  name: string;
+  createdAt: Date;
+  id: string;
+  notes: string | undefined;
+  owner: string | null;
+}
+
+export enum Ticket0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket0Record>();
+  #status: Ticket0Status = Ticket0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket0Record]>): Ticket0Repository<T> {
+    const repository = new Ticket0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket0(records: readonly Ticket0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket0 = async (stream: Readable): Promise<Ticket0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket0Record[];
+};
+
+export interface Message0Record {
+  count: number;
+  owner: string | null;
+  id: string;
+  createdAt: Date;
+  tags: string[];
+  total: number;
+}
+
+export enum Message0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message0Record>();
+  #status: Message0Status = Message0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message0Record]>): Message0Repository<T> {
+    const repository = new Message0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage0(records: readonly Message0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage0 = async (stream: Readable): Promise<Message0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message0Record[];
+};
+
+export interface Channel0Record {
+  updatedAt: Date;
+  id: string;
+  tags: string[];
+  owner: string | null;
+  createdAt: Date;
+  active: boolean;
+}
+
+export enum Channel0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel0Record>();
+  #status: Channel0Status = Channel0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel0Record]>): Channel0Repository<T> {
+    const repository = new Channel0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel0(records: readonly Channel0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel0 = async (stream: Readable): Promise<Channel0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel0Record[];
+};
+
+export interface Report0Record {
+  name: string;
+  total: number;
+  notes: string | undefined;
+  updatedAt: Date;
+  createdAt: Date;
+  id: string;
+}
+
+export enum Report0Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report0Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report0Record>();
+  #status: Report0Status = Report0Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report0Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report0Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report0 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report0Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report0Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report0Record]>): Report0Repository<T> {
+    const repository = new Report0Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport0(records: readonly Report0Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport0 = async (stream: Readable): Promise<Report0Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report0Record[];
+};
+
+export namespace Batch0 {
+  export function size(): number {
+    return 0;
+  }
+}
+
+export interface User1Record {
+  notes: string | undefined;
+  createdAt: Date;
+  count: number;
+  updatedAt: Date;
+  total: number;
+  name: string;
+}
+
+export enum User1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User1Record>();
+  #status: User1Status = User1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User1Record]>): User1Repository<T> {
+    const repository = new User1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser1(records: readonly User1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser1 = async (stream: Readable): Promise<User1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User1Record[];
+};
+
+export interface Order1Record {
+  id: string;
+  active: boolean;
+  tags: string[];
+  updatedAt: Date;
+  count: number;
+  createdAt: Date;
+}
+
+export enum Order1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order1Record>();
+  #status: Order1Status = Order1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order1Record]>): Order1Repository<T> {
+    const repository = new Order1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder1(records: readonly Order1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder1 = async (stream: Readable): Promise<Order1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order1Record[];
+};
+
+export interface Invoice1Record {
+  count: number;
+  tags: string[];
+  createdAt: Date;
+  name: string;
+  id: string;
+  total: number;
+}
+
+export enum Invoice1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice1Record>();
+  #status: Invoice1Status = Invoice1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice1Record]>): Invoice1Repository<T> {
+    const repository = new Invoice1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice1(records: readonly Invoice1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice1 = async (stream: Readable): Promise<Invoice1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice1Record[];
+};
+
+export interface Product1Record {
+  tags: string[];
+  owner: string | null;
+  id: string;
+  active: boolean;
+  updatedAt: Date;
+  name: string;
+}
+
+export enum Product1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product1Record>();
+  #status: Product1Status = Product1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product1Record]>): Product1Repository<T> {
+    const repository = new Product1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct1(records: readonly Product1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct1 = async (stream: Readable): Promise<Product1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product1Record[];
+};
+
+export interface Session1Record {
+  updatedAt: Date;
+  notes: string | undefined;
+  total: number;
+  owner: string | null;
+  count: number;
+  name: string;
+}
+
+export enum Session1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session1Record>();
+  #status: Session1Status = Session1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session1Record]>): Session1Repository<T> {
+    const repository = new Session1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession1(records: readonly Session1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession1 = async (stream: Readable): Promise<Session1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session1Record[];
+};
+
+export interface Token1Record {
+  tags: string[];
+  total: number;
+  createdAt: Date;
+  updatedAt: Date;
+  owner: string | null;
+  name: string;
+}
+
+export enum Token1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token1Record>();
+  #status: Token1Status = Token1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token1Record]>): Token1Repository<T> {
+    const repository = new Token1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken1(records: readonly Token1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken1 = async (stream: Readable): Promise<Token1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token1Record[];
+};
+
+export interface Account1Record {
+  id: string;
+  owner: string | null;
+  name: string;
+  count: number;
+  createdAt: Date;
+  total: number;
+}
+
+export enum Account1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account1Record>();
+  #status: Account1Status = Account1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account1Record]>): Account1Repository<T> {
+    const repository = new Account1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount1(records: readonly Account1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount1 = async (stream: Readable): Promise<Account1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account1Record[];
+};
+
+export interface Cart1Record {
+  updatedAt: Date;
+  total: number;
+  active: boolean;
+  createdAt: Date;
+  tags: string[];
+  id: string;
+}
+
+export enum Cart1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart1Record>();
+  #status: Cart1Status = Cart1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart1Record]>): Cart1Repository<T> {
+    const repository = new Cart1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart1(records: readonly Cart1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart1 = async (stream: Readable): Promise<Cart1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart1Record[];
+};
+
+export interface Shipment1Record {
+  owner: string | null;
+  id: string;
+  notes: string | undefined;
+  active: boolean;
+  updatedAt: Date;
+  total: number;
+}
+
+export enum Shipment1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment1Record>();
+  #status: Shipment1Status = Shipment1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment1Record]>): Shipment1Repository<T> {
+    const repository = new Shipment1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment1(records: readonly Shipment1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment1 = async (stream: Readable): Promise<Shipment1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment1Record[];
+};
+
+export interface Payment1Record {
+  createdAt: Date;
+  count: number;
+  name: string;
+  updatedAt: Date;
+  notes: string | undefined;
+  id: string;
+}
+
+export enum Payment1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment1Record>();
+  #status: Payment1Status = Payment1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment1Record]>): Payment1Repository<T> {
+    const repository = new Payment1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment1(records: readonly Payment1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment1 = async (stream: Readable): Promise<Payment1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment1Record[];
+};
+
+export interface Review1Record {
+  name: string;
+  updatedAt: Date;
+  notes: string | undefined;
+  id: string;
+  total: number;
+  active: boolean;
+}
+
+export enum Review1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review1Record>();
+  #status: Review1Status = Review1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review1Record]>): Review1Repository<T> {
+    const repository = new Review1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview1(records: readonly Review1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview1 = async (stream: Readable): Promise<Review1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review1Record[];
+};
+
+export interface Category1Record {
+  active: boolean;
+  id: string;
+  updatedAt: Date;
+  owner: string | null;
+  createdAt: Date;
+  notes: string | undefined;
+}
+
+export enum Category1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category1Record>();
+  #status: Category1Status = Category1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category1Record]>): Category1Repository<T> {
+    const repository = new Category1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory1(records: readonly Category1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory1 = async (stream: Readable): Promise<Category1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category1Record[];
+};
+
+export interface Warehouse1Record {
+  tags: string[];
+  name: string;
+  count: number;
+  updatedAt: Date;
+  owner: string | null;
+  notes: string | undefined;
+}
+
+export enum Warehouse1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse1Record>();
+  #status: Warehouse1Status = Warehouse1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse1Record]>): Warehouse1Repository<T> {
+    const repository = new Warehouse1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse1(records: readonly Warehouse1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse1 = async (stream: Readable): Promise<Warehouse1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse1Record[];
+};
+
+export interface Supplier1Record {
+  active: boolean;
+  tags: string[];
+  owner: string | null;
+  updatedAt: Date;
+  createdAt: Date;
+  id: string;
+}
+
+export enum Supplier1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier1Record>();
+  #status: Supplier1Status = Supplier1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier1Record]>): Supplier1Repository<T> {
+    const repository = new Supplier1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier1(records: readonly Supplier1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier1 = async (stream: Readable): Promise<Supplier1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier1Record[];
+};
+
+export interface Coupon1Record {
+  updatedAt: Date;
+  createdAt: Date;
+  count: number;
+  id: string;
+  tags: string[];
+  total: number;
+}
+
+export enum Coupon1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon1Record>();
+  #status: Coupon1Status = Coupon1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon1Record]>): Coupon1Repository<T> {
+    const repository = new Coupon1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon1(records: readonly Coupon1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon1 = async (stream: Readable): Promise<Coupon1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon1Record[];
+};
+
+export interface Refund1Record {
+  name: string;
+  updatedAt: Date;
+  id: string;
+  total: number;
+  tags: string[];
+  active: boolean;
+}
+
+export enum Refund1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund1Record>();
+  #status: Refund1Status = Refund1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund1Record]>): Refund1Repository<T> {
+    const repository = new Refund1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund1(records: readonly Refund1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund1 = async (stream: Readable): Promise<Refund1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund1Record[];
+};
+
+export interface Ticket1Record {
+  createdAt: Date;
+  count: number;
+  notes: string | undefined;
+  active: boolean;
+  tags: string[];
+  updatedAt: Date;
+}
+
+export enum Ticket1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket1Record>();
+  #status: Ticket1Status = Ticket1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket1Record]>): Ticket1Repository<T> {
+    const repository = new Ticket1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket1(records: readonly Ticket1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket1 = async (stream: Readable): Promise<Ticket1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket1Record[];
+};
+
+export interface Message1Record {
+  tags: string[];
+  active: boolean;
+  createdAt: Date;
+  total: number;
+  notes: string | undefined;
+  updatedAt: Date;
+}
+
+export enum Message1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message1Record>();
+  #status: Message1Status = Message1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message1Record]>): Message1Repository<T> {
+    const repository = new Message1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage1(records: readonly Message1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage1 = async (stream: Readable): Promise<Message1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message1Record[];
+};
+
+export interface Channel1Record {
+  count: number;
+  createdAt: Date;
+  active: boolean;
+  total: number;
+  notes: string | undefined;
+  tags: string[];
+}
+
+export enum Channel1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel1Record>();
+  #status: Channel1Status = Channel1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel1Record]>): Channel1Repository<T> {
+    const repository = new Channel1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel1(records: readonly Channel1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel1 = async (stream: Readable): Promise<Channel1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel1Record[];
+};
+
+export interface Report1Record {
+  total: number;
+  id: string;
+  owner: string | null;
+  createdAt: Date;
+  updatedAt: Date;
+  tags: string[];
+}
+
+export enum Report1Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report1Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report1Record>();
+  #status: Report1Status = Report1Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report1Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report1Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report1 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report1Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report1Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report1Record]>): Report1Repository<T> {
+    const repository = new Report1Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport1(records: readonly Report1Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport1 = async (stream: Readable): Promise<Report1Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report1Record[];
+};
+
+export namespace Batch1 {
+  export function size(): number {
+    return 1;
+  }
+}
+
+export interface User2Record {
+  tags: string[];
+  name: string;
+  total: number;
+  owner: string | null;
+  notes: string | undefined;
+  active: boolean;
+}
+
+export enum User2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User2Record>();
+  #status: User2Status = User2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User2Record]>): User2Repository<T> {
+    const repository = new User2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser2(records: readonly User2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser2 = async (stream: Readable): Promise<User2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User2Record[];
+};
+
+export interface Order2Record {
+  total: number;
+  notes: string | undefined;
+  id: string;
+  createdAt: Date;
+  tags: string[];
+  name: string;
+}
+
+export enum Order2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order2Record>();
+  #status: Order2Status = Order2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order2Record]>): Order2Repository<T> {
+    const repository = new Order2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder2(records: readonly Order2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder2 = async (stream: Readable): Promise<Order2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order2Record[];
+};
+
+export interface Invoice2Record {
+  active: boolean;
+  notes: string | undefined;
+  count: number;
+  owner: string | null;
+  tags: string[];
+  createdAt: Date;
+}
+
+export enum Invoice2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice2Record>();
+  #status: Invoice2Status = Invoice2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice2Record]>): Invoice2Repository<T> {
+    const repository = new Invoice2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice2(records: readonly Invoice2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice2 = async (stream: Readable): Promise<Invoice2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice2Record[];
+};
+
+export interface Product2Record {
+  count: number;
+  total: number;
+  name: string;
+  tags: string[];
+  createdAt: Date;
+  active: boolean;
+}
+
+export enum Product2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product2Record>();
+  #status: Product2Status = Product2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product2Record]>): Product2Repository<T> {
+    const repository = new Product2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct2(records: readonly Product2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct2 = async (stream: Readable): Promise<Product2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product2Record[];
+};
+
+export interface Session2Record {
+  name: string;
+  notes: string | undefined;
+  total: number;
+  owner: string | null;
+  active: boolean;
+  updatedAt: Date;
+}
+
+export enum Session2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session2Record>();
+  #status: Session2Status = Session2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session2Record]>): Session2Repository<T> {
+    const repository = new Session2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession2(records: readonly Session2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession2 = async (stream: Readable): Promise<Session2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session2Record[];
+};
+
+export interface Token2Record {
+  id: string;
+  createdAt: Date;
+  tags: string[];
+  owner: string | null;
+  total: number;
+  notes: string | undefined;
+}
+
+export enum Token2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token2Record>();
+  #status: Token2Status = Token2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token2Record]>): Token2Repository<T> {
+    const repository = new Token2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken2(records: readonly Token2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken2 = async (stream: Readable): Promise<Token2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token2Record[];
+};
+
+export interface Account2Record {
+  count: number;
+  active: boolean;
+  tags: string[];
+  notes: string | undefined;
+  owner: string | null;
+  updatedAt: Date;
+}
+
+export enum Account2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account2Record>();
+  #status: Account2Status = Account2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account2Record]>): Account2Repository<T> {
+    const repository = new Account2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount2(records: readonly Account2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount2 = async (stream: Readable): Promise<Account2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account2Record[];
+};
+
+export interface Cart2Record {
+  createdAt: Date;
+  name: string;
+  notes: string | undefined;
+  active: boolean;
+  id: string;
+  tags: string[];
+}
+
+export enum Cart2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart2Record>();
+  #status: Cart2Status = Cart2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart2Record]>): Cart2Repository<T> {
+    const repository = new Cart2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart2(records: readonly Cart2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart2 = async (stream: Readable): Promise<Cart2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart2Record[];
+};
+
+export interface Shipment2Record {
+  notes: string | undefined;
+  count: number;
+  updatedAt: Date;
+  createdAt: Date;
+  name: string;
+  owner: string | null;
+}
+
+export enum Shipment2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment2Record>();
+  #status: Shipment2Status = Shipment2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment2Record]>): Shipment2Repository<T> {
+    const repository = new Shipment2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment2(records: readonly Shipment2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment2 = async (stream: Readable): Promise<Shipment2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment2Record[];
+};
+
+export interface Payment2Record {
+  updatedAt: Date;
+  total: number;
+  owner: string | null;
+  createdAt: Date;
+  id: string;
+  notes: string | undefined;
+}
+
+export enum Payment2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment2Record>();
+  #status: Payment2Status = Payment2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment2Record]>): Payment2Repository<T> {
+    const repository = new Payment2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment2(records: readonly Payment2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment2 = async (stream: Readable): Promise<Payment2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment2Record[];
+};
+
+export interface Review2Record {
+  updatedAt: Date;
+  id: string;
+  total: number;
+  count: number;
+  name: string;
+  createdAt: Date;
+}
+
+export enum Review2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review2Record>();
+  #status: Review2Status = Review2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review2Record]>): Review2Repository<T> {
+    const repository = new Review2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview2(records: readonly Review2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview2 = async (stream: Readable): Promise<Review2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review2Record[];
+};
+
+export interface Category2Record {
+  tags: string[];
+  id: string;
+  createdAt: Date;
+  count: number;
+  active: boolean;
+  name: string;
+}
+
+export enum Category2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category2Record>();
+  #status: Category2Status = Category2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category2Record]>): Category2Repository<T> {
+    const repository = new Category2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory2(records: readonly Category2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory2 = async (stream: Readable): Promise<Category2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category2Record[];
+};
+
+export interface Warehouse2Record {
+  tags: string[];
+  name: string;
+  active: boolean;
+  createdAt: Date;
+  id: string;
+  updatedAt: Date;
+}
+
+export enum Warehouse2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse2Record>();
+  #status: Warehouse2Status = Warehouse2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse2Record]>): Warehouse2Repository<T> {
+    const repository = new Warehouse2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse2(records: readonly Warehouse2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse2 = async (stream: Readable): Promise<Warehouse2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse2Record[];
+};
+
+export interface Supplier2Record {
+  count: number;
+  id: string;
+  createdAt: Date;
+  updatedAt: Date;
+  active: boolean;
+  tags: string[];
+}
+
+export enum Supplier2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier2Record>();
+  #status: Supplier2Status = Supplier2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier2Record]>): Supplier2Repository<T> {
+    const repository = new Supplier2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier2(records: readonly Supplier2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier2 = async (stream: Readable): Promise<Supplier2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier2Record[];
+};
+
+export interface Coupon2Record {
+  createdAt: Date;
+  id: string;
+  count: number;
+  owner: string | null;
+  tags: string[];
+  active: boolean;
+}
+
+export enum Coupon2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon2Record>();
+  #status: Coupon2Status = Coupon2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon2Record]>): Coupon2Repository<T> {
+    const repository = new Coupon2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon2(records: readonly Coupon2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon2 = async (stream: Readable): Promise<Coupon2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon2Record[];
+};
+
+export interface Refund2Record {
+  updatedAt: Date;
+  owner: string | null;
+  name: string;
+  notes: string | undefined;
+  tags: string[];
+  createdAt: Date;
+}
+
+export enum Refund2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund2Record>();
+  #status: Refund2Status = Refund2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund2Record]>): Refund2Repository<T> {
+    const repository = new Refund2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund2(records: readonly Refund2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund2 = async (stream: Readable): Promise<Refund2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund2Record[];
+};
+
+export interface Ticket2Record {
+  name: string;
+  active: boolean;
+  notes: string | undefined;
+  updatedAt: Date;
+  count: number;
+  createdAt: Date;
+}
+
+export enum Ticket2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket2Record>();
+  #status: Ticket2Status = Ticket2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket2Record]>): Ticket2Repository<T> {
+    const repository = new Ticket2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket2(records: readonly Ticket2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket2 = async (stream: Readable): Promise<Ticket2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket2Record[];
+};
+
+export interface Message2Record {
+  name: string;
+  id: string;
+  tags: string[];
+  createdAt: Date;
+  updatedAt: Date;
+  active: boolean;
+}
+
+export enum Message2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message2Record>();
+  #status: Message2Status = Message2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message2Record]>): Message2Repository<T> {
+    const repository = new Message2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage2(records: readonly Message2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage2 = async (stream: Readable): Promise<Message2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message2Record[];
+};
+
+export interface Channel2Record {
+  total: number;
+  createdAt: Date;
+  count: number;
+  owner: string | null;
+  active: boolean;
+  id: string;
+}
+
+export enum Channel2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel2Record>();
+  #status: Channel2Status = Channel2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel2Record]>): Channel2Repository<T> {
+    const repository = new Channel2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel2(records: readonly Channel2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel2 = async (stream: Readable): Promise<Channel2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel2Record[];
+};
+
+export interface Report2Record {
+  name: string;
+  owner: string | null;
+  total: number;
+  count: number;
+  tags: string[];
+  createdAt: Date;
+}
+
+export enum Report2Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report2Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report2Record>();
+  #status: Report2Status = Report2Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report2Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report2Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report2 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report2Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report2Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report2Record]>): Report2Repository<T> {
+    const repository = new Report2Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport2(records: readonly Report2Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport2 = async (stream: Readable): Promise<Report2Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report2Record[];
+};
+
+export namespace Batch2 {
+  export function size(): number {
+    return 2;
+  }
+}
+
+export interface User3Record {
+  createdAt: Date;
+  active: boolean;
+  name: string;
+  id: string;
+  notes: string | undefined;
+  count: number;
+}
+
+export enum User3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User3Record>();
+  #status: User3Status = User3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User3Record]>): User3Repository<T> {
+    const repository = new User3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser3(records: readonly User3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser3 = async (stream: Readable): Promise<User3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User3Record[];
+};
+
+export interface Order3Record {
+  total: number;
+  count: number;
+  id: string;
+  updatedAt: Date;
+  name: string;
+  notes: string | undefined;
+}
+
+export enum Order3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order3Record>();
+  #status: Order3Status = Order3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order3Record]>): Order3Repository<T> {
+    const repository = new Order3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder3(records: readonly Order3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder3 = async (stream: Readable): Promise<Order3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order3Record[];
+};
+
+export interface Invoice3Record {
+  total: number;
+  notes: string | undefined;
+  id: string;
+  active: boolean;
+  name: string;
+  owner: string | null;
+}
+
+export enum Invoice3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice3Record>();
+  #status: Invoice3Status = Invoice3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice3Record]>): Invoice3Repository<T> {
+    const repository = new Invoice3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice3(records: readonly Invoice3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice3 = async (stream: Readable): Promise<Invoice3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice3Record[];
+};
+
+export interface Product3Record {
+  name: string;
+  active: boolean;
+  updatedAt: Date;
+  createdAt: Date;
+  id: string;
+  owner: string | null;
+}
+
+export enum Product3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product3Record>();
+  #status: Product3Status = Product3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product3Record]>): Product3Repository<T> {
+    const repository = new Product3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct3(records: readonly Product3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct3 = async (stream: Readable): Promise<Product3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product3Record[];
+};
+
+export interface Session3Record {
+  owner: string | null;
+  name: string;
+  id: string;
+  notes: string | undefined;
+  active: boolean;
+  count: number;
+}
+
+export enum Session3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session3Record>();
+  #status: Session3Status = Session3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session3Record]>): Session3Repository<T> {
+    const repository = new Session3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession3(records: readonly Session3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession3 = async (stream: Readable): Promise<Session3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session3Record[];
+};
+
+export interface Token3Record {
+  name: string;
+  total: number;
+  count: number;
+  owner: string | null;
+  createdAt: Date;
+  active: boolean;
+}
+
+export enum Token3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token3Record>();
+  #status: Token3Status = Token3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token3Record]>): Token3Repository<T> {
+    const repository = new Token3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken3(records: readonly Token3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken3 = async (stream: Readable): Promise<Token3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token3Record[];
+};
+
+export interface Account3Record {
+  createdAt: Date;
+  total: number;
+  notes: string | undefined;
+  updatedAt: Date;
+  tags: string[];
+  owner: string | null;
+}
+
+export enum Account3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account3Record>();
+  #status: Account3Status = Account3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account3Record]>): Account3Repository<T> {
+    const repository = new Account3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount3(records: readonly Account3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount3 = async (stream: Readable): Promise<Account3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account3Record[];
+};
+
+export interface Cart3Record {
+  id: string;
+  createdAt: Date;
+  active: boolean;
+  count: number;
+  updatedAt: Date;
+  total: number;
+}
+
+export enum Cart3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart3Record>();
+  #status: Cart3Status = Cart3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart3Record]>): Cart3Repository<T> {
+    const repository = new Cart3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart3(records: readonly Cart3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart3 = async (stream: Readable): Promise<Cart3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart3Record[];
+};
+
+export interface Shipment3Record {
+  active: boolean;
+  createdAt: Date;
+  notes: string | undefined;
+  total: number;
+  owner: string | null;
+  name: string;
+}
+
+export enum Shipment3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment3Record>();
+  #status: Shipment3Status = Shipment3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment3Record]>): Shipment3Repository<T> {
+    const repository = new Shipment3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment3(records: readonly Shipment3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment3 = async (stream: Readable): Promise<Shipment3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment3Record[];
+};
+
+export interface Payment3Record {
+  name: string;
+  notes: string | undefined;
+  updatedAt: Date;
+  total: number;
+  createdAt: Date;
+  id: string;
+}
+
+export enum Payment3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment3Record>();
+  #status: Payment3Status = Payment3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment3Record]>): Payment3Repository<T> {
+    const repository = new Payment3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment3(records: readonly Payment3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment3 = async (stream: Readable): Promise<Payment3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment3Record[];
+};
+
+export interface Review3Record {
+  count: number;
+  notes: string | undefined;
+  active: boolean;
+  name: string;
+  total: number;
+  tags: string[];
+}
+
+export enum Review3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review3Record>();
+  #status: Review3Status = Review3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review3Record]>): Review3Repository<T> {
+    const repository = new Review3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview3(records: readonly Review3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview3 = async (stream: Readable): Promise<Review3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review3Record[];
+};
+
+export interface Category3Record {
+  notes: string | undefined;
+  count: number;
+  total: number;
+  active: boolean;
+  updatedAt: Date;
+  tags: string[];
+}
+
+export enum Category3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category3Record>();
+  #status: Category3Status = Category3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category3Record]>): Category3Repository<T> {
+    const repository = new Category3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory3(records: readonly Category3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory3 = async (stream: Readable): Promise<Category3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category3Record[];
+};
+
+export interface Warehouse3Record {
+  createdAt: Date;
+  active: boolean;
+  updatedAt: Date;
+  id: string;
+  total: number;
+  notes: string | undefined;
+}
+
+export enum Warehouse3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse3Record>();
+  #status: Warehouse3Status = Warehouse3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse3Record]>): Warehouse3Repository<T> {
+    const repository = new Warehouse3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse3(records: readonly Warehouse3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse3 = async (stream: Readable): Promise<Warehouse3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse3Record[];
+};
+
+export interface Supplier3Record {
+  notes: string | undefined;
+  name: string;
+  owner: string | null;
+  tags: string[];
+  active: boolean;
+  id: string;
+}
+
+export enum Supplier3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier3Record>();
+  #status: Supplier3Status = Supplier3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier3Record]>): Supplier3Repository<T> {
+    const repository = new Supplier3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier3(records: readonly Supplier3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier3 = async (stream: Readable): Promise<Supplier3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier3Record[];
+};
+
+export interface Coupon3Record {
+  updatedAt: Date;
+  active: boolean;
+  name: string;
+  id: string;
+  tags: string[];
+  owner: string | null;
+}
+
+export enum Coupon3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon3Record>();
+  #status: Coupon3Status = Coupon3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon3Record]>): Coupon3Repository<T> {
+    const repository = new Coupon3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon3(records: readonly Coupon3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon3 = async (stream: Readable): Promise<Coupon3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon3Record[];
+};
+
+export interface Refund3Record {
+  active: boolean;
+  id: string;
+  name: string;
+  count: number;
+  notes: string | undefined;
+  tags: string[];
+}
+
+export enum Refund3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund3Record>();
+  #status: Refund3Status = Refund3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund3Record]>): Refund3Repository<T> {
+    const repository = new Refund3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund3(records: readonly Refund3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund3 = async (stream: Readable): Promise<Refund3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund3Record[];
+};
+
+export interface Ticket3Record {
+  updatedAt: Date;
+  notes: string | undefined;
+  id: string;
+  name: string;
+  total: number;
+  tags: string[];
+}
+
+export enum Ticket3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket3Record>();
+  #status: Ticket3Status = Ticket3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket3Record]>): Ticket3Repository<T> {
+    const repository = new Ticket3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket3(records: readonly Ticket3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket3 = async (stream: Readable): Promise<Ticket3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket3Record[];
+};
+
+export interface Message3Record {
+  count: number;
+  id: string;
+  updatedAt: Date;
+  owner: string | null;
+  active: boolean;
+  tags: string[];
+}
+
+export enum Message3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message3Record>();
+  #status: Message3Status = Message3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message3Record]>): Message3Repository<T> {
+    const repository = new Message3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage3(records: readonly Message3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage3 = async (stream: Readable): Promise<Message3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message3Record[];
+};
+
+export interface Channel3Record {
+  owner: string | null;
+  notes: string | undefined;
+  count: number;
+  name: string;
+  updatedAt: Date;
+  tags: string[];
+}
+
+export enum Channel3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel3Record>();
+  #status: Channel3Status = Channel3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel3Record]>): Channel3Repository<T> {
+    const repository = new Channel3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel3(records: readonly Channel3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel3 = async (stream: Readable): Promise<Channel3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel3Record[];
+};
+
+export interface Report3Record {
+  active: boolean;
+  total: number;
+  tags: string[];
+  id: string;
+  notes: string | undefined;
+  owner: string | null;
+}
+
+export enum Report3Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report3Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report3Record>();
+  #status: Report3Status = Report3Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report3Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report3Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report3 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report3Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report3Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report3Record]>): Report3Repository<T> {
+    const repository = new Report3Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport3(records: readonly Report3Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport3 = async (stream: Readable): Promise<Report3Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report3Record[];
+};
+
+export namespace Batch3 {
+  export function size(): number {
+    return 3;
+  }
+}
+
+export interface User4Record {
+  total: number;
+  id: string;
+  count: number;
+  tags: string[];
+  owner: string | null;
+  updatedAt: Date;
+}
+
+export enum User4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User4Record>();
+  #status: User4Status = User4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User4Record]>): User4Repository<T> {
+    const repository = new User4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser4(records: readonly User4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser4 = async (stream: Readable): Promise<User4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User4Record[];
+};
+
+export interface Order4Record {
+  owner: string | null;
+  total: number;
+  count: number;
+  id: string;
+  updatedAt: Date;
+  tags: string[];
+}
+
+export enum Order4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order4Record>();
+  #status: Order4Status = Order4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order4Record]>): Order4Repository<T> {
+    const repository = new Order4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder4(records: readonly Order4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder4 = async (stream: Readable): Promise<Order4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order4Record[];
+};
+
+export interface Invoice4Record {
+  active: boolean;
+  updatedAt: Date;
+  owner: string | null;
+  createdAt: Date;
+  notes: string | undefined;
+  id: string;
+}
+
+export enum Invoice4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice4Record>();
+  #status: Invoice4Status = Invoice4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice4Record]>): Invoice4Repository<T> {
+    const repository = new Invoice4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice4(records: readonly Invoice4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice4 = async (stream: Readable): Promise<Invoice4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice4Record[];
+};
+
+export interface Product4Record {
+  owner: string | null;
+  active: boolean;
+  count: number;
+  updatedAt: Date;
+  name: string;
+  notes: string | undefined;
+}
+
+export enum Product4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product4Record>();
+  #status: Product4Status = Product4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product4Record]>): Product4Repository<T> {
+    const repository = new Product4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct4(records: readonly Product4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct4 = async (stream: Readable): Promise<Product4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product4Record[];
+};
+
+export interface Session4Record {
+  active: boolean;
+  updatedAt: Date;
+  name: string;
+  id: string;
+  total: number;
+  count: number;
+}
+
+export enum Session4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session4Record>();
+  #status: Session4Status = Session4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session4Record]>): Session4Repository<T> {
+    const repository = new Session4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession4(records: readonly Session4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession4 = async (stream: Readable): Promise<Session4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session4Record[];
+};
+
+export interface Token4Record {
+  id: string;
+  count: number;
+  createdAt: Date;
+  notes: string | undefined;
+  owner: string | null;
+  active: boolean;
+}
+
+export enum Token4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token4Record>();
+  #status: Token4Status = Token4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token4Record]>): Token4Repository<T> {
+    const repository = new Token4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken4(records: readonly Token4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken4 = async (stream: Readable): Promise<Token4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token4Record[];
+};
+
+export interface Account4Record {
+  owner: string | null;
+  name: string;
+  updatedAt: Date;
+  active: boolean;
+  id: string;
+  createdAt: Date;
+}
+
+export enum Account4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account4Record>();
+  #status: Account4Status = Account4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account4Record]>): Account4Repository<T> {
+    const repository = new Account4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount4(records: readonly Account4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount4 = async (stream: Readable): Promise<Account4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account4Record[];
+};
+
+export interface Cart4Record {
+  active: boolean;
+  name: string;
+  total: number;
+  id: string;
+  updatedAt: Date;
+  tags: string[];
+}
+
+export enum Cart4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart4Record>();
+  #status: Cart4Status = Cart4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart4Record]>): Cart4Repository<T> {
+    const repository = new Cart4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart4(records: readonly Cart4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart4 = async (stream: Readable): Promise<Cart4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart4Record[];
+};
+
+export interface Shipment4Record {
+  tags: string[];
+  count: number;
+  id: string;
+  total: number;
+  owner: string | null;
+  updatedAt: Date;
+}
+
+export enum Shipment4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment4Record>();
+  #status: Shipment4Status = Shipment4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment4Record]>): Shipment4Repository<T> {
+    const repository = new Shipment4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment4(records: readonly Shipment4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment4 = async (stream: Readable): Promise<Shipment4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment4Record[];
+};
+
+export interface Payment4Record {
+  owner: string | null;
+  id: string;
+  total: number;
+  active: boolean;
+  count: number;
+  updatedAt: Date;
+}
+
+export enum Payment4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment4Record>();
+  #status: Payment4Status = Payment4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment4Record]>): Payment4Repository<T> {
+    const repository = new Payment4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment4(records: readonly Payment4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment4 = async (stream: Readable): Promise<Payment4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment4Record[];
+};
+
+export interface Review4Record {
+  active: boolean;
+  tags: string[];
+  id: string;
+  owner: string | null;
+  createdAt: Date;
+  notes: string | undefined;
+}
+
+export enum Review4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review4Record>();
+  #status: Review4Status = Review4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review4Record]>): Review4Repository<T> {
+    const repository = new Review4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview4(records: readonly Review4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview4 = async (stream: Readable): Promise<Review4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review4Record[];
+};
+
+export interface Category4Record {
+  name: string;
+  count: number;
+  owner: string | null;
+  createdAt: Date;
+  notes: string | undefined;
+  active: boolean;
+}
+
+export enum Category4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category4Record>();
+  #status: Category4Status = Category4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category4Record]>): Category4Repository<T> {
+    const repository = new Category4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory4(records: readonly Category4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory4 = async (stream: Readable): Promise<Category4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category4Record[];
+};
+
+export interface Warehouse4Record {
+  notes: string | undefined;
+  total: number;
+  count: number;
+  id: string;
+  tags: string[];
+  owner: string | null;
+}
+
+export enum Warehouse4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse4Record>();
+  #status: Warehouse4Status = Warehouse4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse4Record]>): Warehouse4Repository<T> {
+    const repository = new Warehouse4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse4(records: readonly Warehouse4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse4 = async (stream: Readable): Promise<Warehouse4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse4Record[];
+};
+
+export interface Supplier4Record {
+  tags: string[];
+  updatedAt: Date;
+  active: boolean;
+  notes: string | undefined;
+  count: number;
+  total: number;
+}
+
+export enum Supplier4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier4Record>();
+  #status: Supplier4Status = Supplier4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier4Record]>): Supplier4Repository<T> {
+    const repository = new Supplier4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier4(records: readonly Supplier4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier4 = async (stream: Readable): Promise<Supplier4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier4Record[];
+};
+
+export interface Coupon4Record {
+  name: string;
+  total: number;
+  active: boolean;
+  createdAt: Date;
+  owner: string | null;
+  count: number;
+}
+
+export enum Coupon4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon4Record>();
+  #status: Coupon4Status = Coupon4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon4Record]>): Coupon4Repository<T> {
+    const repository = new Coupon4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon4(records: readonly Coupon4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon4 = async (stream: Readable): Promise<Coupon4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon4Record[];
+};
+
+export interface Refund4Record {
+  total: number;
+  id: string;
+  notes: string | undefined;
+  createdAt: Date;
+  updatedAt: Date;
+  owner: string | null;
+}
+
+export enum Refund4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund4Record>();
+  #status: Refund4Status = Refund4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund4Record]>): Refund4Repository<T> {
+    const repository = new Refund4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund4(records: readonly Refund4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund4 = async (stream: Readable): Promise<Refund4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund4Record[];
+};
+
+export interface Ticket4Record {
+  total: number;
+  notes: string | undefined;
+  id: string;
+  count: number;
+  active: boolean;
+  createdAt: Date;
+}
+
+export enum Ticket4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket4Record>();
+  #status: Ticket4Status = Ticket4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket4Record]>): Ticket4Repository<T> {
+    const repository = new Ticket4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket4(records: readonly Ticket4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket4 = async (stream: Readable): Promise<Ticket4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket4Record[];
+};
+
+export interface Message4Record {
+  count: number;
+  active: boolean;
+  owner: string | null;
+  name: string;
+  updatedAt: Date;
+  id: string;
+}
+
+export enum Message4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message4Record>();
+  #status: Message4Status = Message4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message4Record]>): Message4Repository<T> {
+    const repository = new Message4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage4(records: readonly Message4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage4 = async (stream: Readable): Promise<Message4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message4Record[];
+};
+
+export interface Channel4Record {
+  total: number;
+  count: number;
+  id: string;
+  owner: string | null;
+  name: string;
+  notes: string | undefined;
+}
+
+export enum Channel4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel4Record>();
+  #status: Channel4Status = Channel4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel4Record]>): Channel4Repository<T> {
+    const repository = new Channel4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel4(records: readonly Channel4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel4 = async (stream: Readable): Promise<Channel4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel4Record[];
+};
+
+export interface Report4Record {
+  tags: string[];
+  active: boolean;
+  total: number;
+  id: string;
+  owner: string | null;
+  updatedAt: Date;
+}
+
+export enum Report4Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report4Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report4Record>();
+  #status: Report4Status = Report4Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report4Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report4Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report4 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report4Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report4Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report4Record]>): Report4Repository<T> {
+    const repository = new Report4Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport4(records: readonly Report4Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport4 = async (stream: Readable): Promise<Report4Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report4Record[];
+};
+
+export namespace Batch4 {
+  export function size(): number {
+    return 4;
+  }
+}
+
+export interface User5Record {
+  tags: string[];
+  count: number;
+  notes: string | undefined;
+  active: boolean;
+  createdAt: Date;
+  id: string;
+}
+
+export enum User5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User5Record>();
+  #status: User5Status = User5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User5Record]>): User5Repository<T> {
+    const repository = new User5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser5(records: readonly User5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser5 = async (stream: Readable): Promise<User5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User5Record[];
+};
+
+export interface Order5Record {
+  owner: string | null;
+  total: number;
+  count: number;
+  notes: string | undefined;
+  createdAt: Date;
+  name: string;
+}
+
+export enum Order5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order5Record>();
+  #status: Order5Status = Order5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order5Record]>): Order5Repository<T> {
+    const repository = new Order5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder5(records: readonly Order5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder5 = async (stream: Readable): Promise<Order5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order5Record[];
+};
+
+export interface Invoice5Record {
+  id: string;
+  owner: string | null;
+  active: boolean;
+  count: number;
+  tags: string[];
+  createdAt: Date;
+}
+
+export enum Invoice5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice5Record>();
+  #status: Invoice5Status = Invoice5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice5Record]>): Invoice5Repository<T> {
+    const repository = new Invoice5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice5(records: readonly Invoice5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice5 = async (stream: Readable): Promise<Invoice5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice5Record[];
+};
+
+export interface Product5Record {
+  active: boolean;
+  total: number;
+  notes: string | undefined;
+  id: string;
+  name: string;
+  createdAt: Date;
+}
+
+export enum Product5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product5Record>();
+  #status: Product5Status = Product5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product5Record]>): Product5Repository<T> {
+    const repository = new Product5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct5(records: readonly Product5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct5 = async (stream: Readable): Promise<Product5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product5Record[];
+};
+
+export interface Session5Record {
+  notes: string | undefined;
+  count: number;
+  total: number;
+  owner: string | null;
+  active: boolean;
+  updatedAt: Date;
+}
+
+export enum Session5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session5Record>();
+  #status: Session5Status = Session5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session5Record]>): Session5Repository<T> {
+    const repository = new Session5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession5(records: readonly Session5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession5 = async (stream: Readable): Promise<Session5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session5Record[];
+};
+
+export interface Token5Record {
+  createdAt: Date;
+  active: boolean;
+  tags: string[];
+  count: number;
+  updatedAt: Date;
+  id: string;
+}
+
+export enum Token5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token5Record>();
+  #status: Token5Status = Token5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token5Record]>): Token5Repository<T> {
+    const repository = new Token5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken5(records: readonly Token5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken5 = async (stream: Readable): Promise<Token5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token5Record[];
+};
+
+export interface Account5Record {
+  tags: string[];
+  id: string;
+  createdAt: Date;
+  owner: string | null;
+  active: boolean;
+  updatedAt: Date;
+}
+
+export enum Account5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account5Record>();
+  #status: Account5Status = Account5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account5Record]>): Account5Repository<T> {
+    const repository = new Account5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount5(records: readonly Account5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount5 = async (stream: Readable): Promise<Account5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account5Record[];
+};
+
+export interface Cart5Record {
+  id: string;
+  updatedAt: Date;
+  owner: string | null;
+  notes: string | undefined;
+  createdAt: Date;
+  total: number;
+}
+
+export enum Cart5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart5Record>();
+  #status: Cart5Status = Cart5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart5Record]>): Cart5Repository<T> {
+    const repository = new Cart5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart5(records: readonly Cart5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart5 = async (stream: Readable): Promise<Cart5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart5Record[];
+};
+
+export interface Shipment5Record {
+  count: number;
+  owner: string | null;
+  updatedAt: Date;
+  tags: string[];
+  name: string;
+  total: number;
+}
+
+export enum Shipment5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment5Record>();
+  #status: Shipment5Status = Shipment5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment5Record]>): Shipment5Repository<T> {
+    const repository = new Shipment5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment5(records: readonly Shipment5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment5 = async (stream: Readable): Promise<Shipment5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment5Record[];
+};
+
+export interface Payment5Record {
+  total: number;
+  createdAt: Date;
+  notes: string | undefined;
+  updatedAt: Date;
+  owner: string | null;
+  tags: string[];
+}
+
+export enum Payment5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment5Record>();
+  #status: Payment5Status = Payment5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment5Record]>): Payment5Repository<T> {
+    const repository = new Payment5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment5(records: readonly Payment5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment5 = async (stream: Readable): Promise<Payment5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment5Record[];
+};
+
+export interface Review5Record {
+  active: boolean;
+  updatedAt: Date;
+  notes: string | undefined;
+  name: string;
+  createdAt: Date;
+  total: number;
+}
+
+export enum Review5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review5Record>();
+  #status: Review5Status = Review5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review5Record]>): Review5Repository<T> {
+    const repository = new Review5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview5(records: readonly Review5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview5 = async (stream: Readable): Promise<Review5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review5Record[];
+};
+
+export interface Category5Record {
+  notes: string | undefined;
+  updatedAt: Date;
+  createdAt: Date;
+  active: boolean;
+  total: number;
+  tags: string[];
+}
+
+export enum Category5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category5Record>();
+  #status: Category5Status = Category5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category5Record]>): Category5Repository<T> {
+    const repository = new Category5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory5(records: readonly Category5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory5 = async (stream: Readable): Promise<Category5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category5Record[];
+};
+
+export interface Warehouse5Record {
+  count: number;
+  tags: string[];
+  name: string;
+  updatedAt: Date;
+  owner: string | null;
+  id: string;
+}
+
+export enum Warehouse5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse5Record>();
+  #status: Warehouse5Status = Warehouse5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse5Record]>): Warehouse5Repository<T> {
+    const repository = new Warehouse5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse5(records: readonly Warehouse5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse5 = async (stream: Readable): Promise<Warehouse5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse5Record[];
+};
+
+export interface Supplier5Record {
+  id: string;
+  owner: string | null;
+  updatedAt: Date;
+  createdAt: Date;
+  tags: string[];
+  name: string;
+}
+
+export enum Supplier5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier5Record>();
+  #status: Supplier5Status = Supplier5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier5Record]>): Supplier5Repository<T> {
+    const repository = new Supplier5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier5(records: readonly Supplier5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier5 = async (stream: Readable): Promise<Supplier5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier5Record[];
+};
+
+export interface Coupon5Record {
+  total: number;
+  id: string;
+  count: number;
+  notes: string | undefined;
+  active: boolean;
+  tags: string[];
+}
+
+export enum Coupon5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon5Record>();
+  #status: Coupon5Status = Coupon5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon5Record]>): Coupon5Repository<T> {
+    const repository = new Coupon5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon5(records: readonly Coupon5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon5 = async (stream: Readable): Promise<Coupon5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon5Record[];
+};
+
+export interface Refund5Record {
+  name: string;
+  createdAt: Date;
+  id: string;
+  updatedAt: Date;
+  total: number;
+  owner: string | null;
+}
+
+export enum Refund5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund5Record>();
+  #status: Refund5Status = Refund5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund5Record]>): Refund5Repository<T> {
+    const repository = new Refund5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund5(records: readonly Refund5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund5 = async (stream: Readable): Promise<Refund5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund5Record[];
+};
+
+export interface Ticket5Record {
+  id: string;
+  owner: string | null;
+  createdAt: Date;
+  updatedAt: Date;
+  total: number;
+  name: string;
+}
+
+export enum Ticket5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket5Record>();
+  #status: Ticket5Status = Ticket5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket5Record]>): Ticket5Repository<T> {
+    const repository = new Ticket5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket5(records: readonly Ticket5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket5 = async (stream: Readable): Promise<Ticket5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket5Record[];
+};
+
+export interface Message5Record {
+  name: string;
+  id: string;
+  updatedAt: Date;
+  active: boolean;
+  createdAt: Date;
+  notes: string | undefined;
+}
+
+export enum Message5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message5Record>();
+  #status: Message5Status = Message5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message5Record]>): Message5Repository<T> {
+    const repository = new Message5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage5(records: readonly Message5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage5 = async (stream: Readable): Promise<Message5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message5Record[];
+};
+
+export interface Channel5Record {
+  count: number;
+  createdAt: Date;
+  active: boolean;
+  id: string;
+  owner: string | null;
+  name: string;
+}
+
+export enum Channel5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel5Record>();
+  #status: Channel5Status = Channel5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel5Record]>): Channel5Repository<T> {
+    const repository = new Channel5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel5(records: readonly Channel5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel5 = async (stream: Readable): Promise<Channel5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel5Record[];
+};
+
+export interface Report5Record {
+  tags: string[];
+  owner: string | null;
+  name: string;
+  total: number;
+  active: boolean;
+  createdAt: Date;
+}
+
+export enum Report5Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report5Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report5Record>();
+  #status: Report5Status = Report5Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report5Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report5Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report5 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report5Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report5Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report5Record]>): Report5Repository<T> {
+    const repository = new Report5Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport5(records: readonly Report5Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport5 = async (stream: Readable): Promise<Report5Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report5Record[];
+};
+
+export namespace Batch5 {
+  export function size(): number {
+    return 5;
+  }
+}
+
+export interface User6Record {
+  active: boolean;
+  updatedAt: Date;
+  owner: string | null;
+  createdAt: Date;
+  count: number;
+  tags: string[];
+}
+
+export enum User6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User6Record>();
+  #status: User6Status = User6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User6Record]>): User6Repository<T> {
+    const repository = new User6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser6(records: readonly User6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser6 = async (stream: Readable): Promise<User6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User6Record[];
+};
+
+export interface Order6Record {
+  name: string;
+  tags: string[];
+  id: string;
+  notes: string | undefined;
+  updatedAt: Date;
+  createdAt: Date;
+}
+
+export enum Order6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order6Record>();
+  #status: Order6Status = Order6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order6Record]>): Order6Repository<T> {
+    const repository = new Order6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder6(records: readonly Order6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder6 = async (stream: Readable): Promise<Order6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order6Record[];
+};
+
+export interface Invoice6Record {
+  total: number;
+  active: boolean;
+  count: number;
+  updatedAt: Date;
+  tags: string[];
+  name: string;
+}
+
+export enum Invoice6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice6Record>();
+  #status: Invoice6Status = Invoice6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice6Record]>): Invoice6Repository<T> {
+    const repository = new Invoice6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice6(records: readonly Invoice6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice6 = async (stream: Readable): Promise<Invoice6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice6Record[];
+};
+
+export interface Product6Record {
+  tags: string[];
+  notes: string | undefined;
+  count: number;
+  updatedAt: Date;
+  name: string;
+  createdAt: Date;
+}
+
+export enum Product6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product6Record>();
+  #status: Product6Status = Product6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product6Record]>): Product6Repository<T> {
+    const repository = new Product6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct6(records: readonly Product6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct6 = async (stream: Readable): Promise<Product6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product6Record[];
+};
+
+export interface Session6Record {
+  count: number;
+  tags: string[];
+  updatedAt: Date;
+  name: string;
+  createdAt: Date;
+  active: boolean;
+}
+
+export enum Session6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session6Record>();
+  #status: Session6Status = Session6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session6Record]>): Session6Repository<T> {
+    const repository = new Session6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession6(records: readonly Session6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession6 = async (stream: Readable): Promise<Session6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session6Record[];
+};
+
+export interface Token6Record {
+  updatedAt: Date;
+  tags: string[];
+  total: number;
+  name: string;
+  createdAt: Date;
+  notes: string | undefined;
+}
+
+export enum Token6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token6Record>();
+  #status: Token6Status = Token6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token6Record]>): Token6Repository<T> {
+    const repository = new Token6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken6(records: readonly Token6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken6 = async (stream: Readable): Promise<Token6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token6Record[];
+};
+
+export interface Account6Record {
+  total: number;
+  tags: string[];
+  count: number;
+  notes: string | undefined;
+  createdAt: Date;
+  id: string;
+}
+
+export enum Account6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account6Record>();
+  #status: Account6Status = Account6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account6Record]>): Account6Repository<T> {
+    const repository = new Account6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount6(records: readonly Account6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount6 = async (stream: Readable): Promise<Account6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account6Record[];
+};
+
+export interface Cart6Record {
+  notes: string | undefined;
+  id: string;
+  count: number;
+  updatedAt: Date;
+  active: boolean;
+  createdAt: Date;
+}
+
+export enum Cart6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart6Record>();
+  #status: Cart6Status = Cart6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart6Record]>): Cart6Repository<T> {
+    const repository = new Cart6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart6(records: readonly Cart6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart6 = async (stream: Readable): Promise<Cart6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart6Record[];
+};
+
+export interface Shipment6Record {
+  name: string;
+  id: string;
+  tags: string[];
+  createdAt: Date;
+  total: number;
+  owner: string | null;
+}
+
+export enum Shipment6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment6Record>();
+  #status: Shipment6Status = Shipment6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment6Record]>): Shipment6Repository<T> {
+    const repository = new Shipment6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment6(records: readonly Shipment6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment6 = async (stream: Readable): Promise<Shipment6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment6Record[];
+};
+
+export interface Payment6Record {
+  name: string;
+  notes: string | undefined;
+  owner: string | null;
+  tags: string[];
+  active: boolean;
+  total: number;
+}
+
+export enum Payment6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment6Record>();
+  #status: Payment6Status = Payment6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment6Record]>): Payment6Repository<T> {
+    const repository = new Payment6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment6(records: readonly Payment6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment6 = async (stream: Readable): Promise<Payment6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment6Record[];
+};
+
+export interface Review6Record {
+  active: boolean;
+  tags: string[];
+  notes: string | undefined;
+  id: string;
+  updatedAt: Date;
+  total: number;
+}
+
+export enum Review6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review6Record>();
+  #status: Review6Status = Review6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review6Record]>): Review6Repository<T> {
+    const repository = new Review6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview6(records: readonly Review6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview6 = async (stream: Readable): Promise<Review6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review6Record[];
+};
+
+export interface Category6Record {
+  active: boolean;
+  tags: string[];
+  updatedAt: Date;
+  id: string;
+  owner: string | null;
+  total: number;
+}
+
+export enum Category6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category6Record>();
+  #status: Category6Status = Category6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category6Record]>): Category6Repository<T> {
+    const repository = new Category6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory6(records: readonly Category6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory6 = async (stream: Readable): Promise<Category6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category6Record[];
+};
+
+export interface Warehouse6Record {
+  active: boolean;
+  notes: string | undefined;
+  total: number;
+  id: string;
+  tags: string[];
+  updatedAt: Date;
+}
+
+export enum Warehouse6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse6Record>();
+  #status: Warehouse6Status = Warehouse6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse6Record]>): Warehouse6Repository<T> {
+    const repository = new Warehouse6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse6(records: readonly Warehouse6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse6 = async (stream: Readable): Promise<Warehouse6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse6Record[];
+};
+
+export interface Supplier6Record {
+  notes: string | undefined;
+  total: number;
+  owner: string | null;
+  count: number;
+  createdAt: Date;
+  name: string;
+}
+
+export enum Supplier6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier6Record>();
+  #status: Supplier6Status = Supplier6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier6Record]>): Supplier6Repository<T> {
+    const repository = new Supplier6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier6(records: readonly Supplier6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier6 = async (stream: Readable): Promise<Supplier6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier6Record[];
+};
+
+export interface Coupon6Record {
+  notes: string | undefined;
+  updatedAt: Date;
+  tags: string[];
+  owner: string | null;
+  count: number;
+  active: boolean;
+}
+
+export enum Coupon6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon6Record>();
+  #status: Coupon6Status = Coupon6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon6Record]>): Coupon6Repository<T> {
+    const repository = new Coupon6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon6(records: readonly Coupon6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon6 = async (stream: Readable): Promise<Coupon6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon6Record[];
+};
+
+export interface Refund6Record {
+  owner: string | null;
+  updatedAt: Date;
+  tags: string[];
+  id: string;
+  name: string;
+  count: number;
+}
+
+export enum Refund6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund6Record>();
+  #status: Refund6Status = Refund6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund6Record]>): Refund6Repository<T> {
+    const repository = new Refund6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund6(records: readonly Refund6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund6 = async (stream: Readable): Promise<Refund6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund6Record[];
+};
+
+export interface Ticket6Record {
+  id: string;
+  name: string;
+  owner: string | null;
+  createdAt: Date;
+  tags: string[];
+  active: boolean;
+}
+
+export enum Ticket6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket6Record>();
+  #status: Ticket6Status = Ticket6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket6Record]>): Ticket6Repository<T> {
+    const repository = new Ticket6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket6(records: readonly Ticket6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket6 = async (stream: Readable): Promise<Ticket6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket6Record[];
+};
+
+export interface Message6Record {
+  tags: string[];
+  total: number;
+  owner: string | null;
+  active: boolean;
+  count: number;
+  updatedAt: Date;
+}
+
+export enum Message6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message6Record>();
+  #status: Message6Status = Message6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message6Record]>): Message6Repository<T> {
+    const repository = new Message6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage6(records: readonly Message6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage6 = async (stream: Readable): Promise<Message6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message6Record[];
+};
+
+export interface Channel6Record {
+  name: string;
+  count: number;
+  total: number;
+  id: string;
+  notes: string | undefined;
+  updatedAt: Date;
+}
+
+export enum Channel6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel6Record>();
+  #status: Channel6Status = Channel6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel6Record]>): Channel6Repository<T> {
+    const repository = new Channel6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel6(records: readonly Channel6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel6 = async (stream: Readable): Promise<Channel6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel6Record[];
+};
+
+export interface Report6Record {
+  total: number;
+  owner: string | null;
+  id: string;
+  tags: string[];
+  createdAt: Date;
+  notes: string | undefined;
+}
+
+export enum Report6Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report6Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report6Record>();
+  #status: Report6Status = Report6Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report6Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report6Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report6 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report6Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report6Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report6Record]>): Report6Repository<T> {
+    const repository = new Report6Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport6(records: readonly Report6Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport6 = async (stream: Readable): Promise<Report6Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report6Record[];
+};
+
+export namespace Batch6 {
+  export function size(): number {
+    return 6;
+  }
+}
+
+export interface User7Record {
+  updatedAt: Date;
+  active: boolean;
+  tags: string[];
+  count: number;
+  total: number;
+  id: string;
+}
+
+export enum User7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User7Record>();
+  #status: User7Status = User7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User7Record]>): User7Repository<T> {
+    const repository = new User7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser7(records: readonly User7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser7 = async (stream: Readable): Promise<User7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User7Record[];
+};
+
+export interface Order7Record {
+  total: number;
+  id: string;
+  updatedAt: Date;
+  active: boolean;
+  count: number;
+  owner: string | null;
+}
+
+export enum Order7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order7Record>();
+  #status: Order7Status = Order7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order7Record]>): Order7Repository<T> {
+    const repository = new Order7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder7(records: readonly Order7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder7 = async (stream: Readable): Promise<Order7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order7Record[];
+};
+
+export interface Invoice7Record {
+  tags: string[];
+  notes: string | undefined;
+  createdAt: Date;
+  total: number;
+  id: string;
+  active: boolean;
+}
+
+export enum Invoice7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice7Record>();
+  #status: Invoice7Status = Invoice7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice7Record]>): Invoice7Repository<T> {
+    const repository = new Invoice7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice7(records: readonly Invoice7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice7 = async (stream: Readable): Promise<Invoice7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice7Record[];
+};
+
+export interface Product7Record {
+  name: string;
+  updatedAt: Date;
+  owner: string | null;
+  createdAt: Date;
+  tags: string[];
+  id: string;
+}
+
+export enum Product7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product7Record>();
+  #status: Product7Status = Product7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product7Record]>): Product7Repository<T> {
+    const repository = new Product7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct7(records: readonly Product7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct7 = async (stream: Readable): Promise<Product7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product7Record[];
+};
+
+export interface Session7Record {
+  updatedAt: Date;
+  owner: string | null;
+  tags: string[];
+  total: number;
+  id: string;
+  active: boolean;
+}
+
+export enum Session7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session7Record>();
+  #status: Session7Status = Session7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session7Record]>): Session7Repository<T> {
+    const repository = new Session7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession7(records: readonly Session7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession7 = async (stream: Readable): Promise<Session7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session7Record[];
+};
+
+export interface Token7Record {
+  active: boolean;
+  id: string;
+  createdAt: Date;
+  tags: string[];
+  total: number;
+  count: number;
+}
+
+export enum Token7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token7Record>();
+  #status: Token7Status = Token7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token7Record]>): Token7Repository<T> {
+    const repository = new Token7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken7(records: readonly Token7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken7 = async (stream: Readable): Promise<Token7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token7Record[];
+};
+
+export interface Account7Record {
+  notes: string | undefined;
+  updatedAt: Date;
+  total: number;
+  tags: string[];
+  createdAt: Date;
+  id: string;
+}
+
+export enum Account7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account7Record>();
+  #status: Account7Status = Account7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account7Record]>): Account7Repository<T> {
+    const repository = new Account7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount7(records: readonly Account7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount7 = async (stream: Readable): Promise<Account7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account7Record[];
+};
+
+export interface Cart7Record {
+  notes: string | undefined;
+  total: number;
+  count: number;
+  name: string;
+  owner: string | null;
+  active: boolean;
+}
+
+export enum Cart7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart7Record>();
+  #status: Cart7Status = Cart7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart7Record]>): Cart7Repository<T> {
+    const repository = new Cart7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart7(records: readonly Cart7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart7 = async (stream: Readable): Promise<Cart7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart7Record[];
+};
+
+export interface Shipment7Record {
+  owner: string | null;
+  active: boolean;
+  createdAt: Date;
+  updatedAt: Date;
+  tags: string[];
+  notes: string | undefined;
+}
+
+export enum Shipment7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment7Record>();
+  #status: Shipment7Status = Shipment7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment7Record]>): Shipment7Repository<T> {
+    const repository = new Shipment7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment7(records: readonly Shipment7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment7 = async (stream: Readable): Promise<Shipment7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment7Record[];
+};
+
+export interface Payment7Record {
+  total: number;
+  id: string;
+  count: number;
+  updatedAt: Date;
+  name: string;
+  active: boolean;
+}
+
+export enum Payment7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment7Record>();
+  #status: Payment7Status = Payment7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment7Record]>): Payment7Repository<T> {
+    const repository = new Payment7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment7(records: readonly Payment7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment7 = async (stream: Readable): Promise<Payment7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment7Record[];
+};
+
+export interface Review7Record {
+  active: boolean;
+  notes: string | undefined;
+  count: number;
+  id: string;
+  name: string;
+  total: number;
+}
+
+export enum Review7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review7Record>();
+  #status: Review7Status = Review7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review7Record]>): Review7Repository<T> {
+    const repository = new Review7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview7(records: readonly Review7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview7 = async (stream: Readable): Promise<Review7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review7Record[];
+};
+
+export interface Category7Record {
+  createdAt: Date;
+  notes: string | undefined;
+  count: number;
+  updatedAt: Date;
+  active: boolean;
+  tags: string[];
+}
+
+export enum Category7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category7Record>();
+  #status: Category7Status = Category7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category7Record]>): Category7Repository<T> {
+    const repository = new Category7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory7(records: readonly Category7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory7 = async (stream: Readable): Promise<Category7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category7Record[];
+};
+
+export interface Warehouse7Record {
+  name: string;
+  tags: string[];
+  count: number;
+  id: string;
+  total: number;
+  updatedAt: Date;
+}
+
+export enum Warehouse7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse7Record>();
+  #status: Warehouse7Status = Warehouse7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse7Record]>): Warehouse7Repository<T> {
+    const repository = new Warehouse7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse7(records: readonly Warehouse7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse7 = async (stream: Readable): Promise<Warehouse7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse7Record[];
+};
+
+export interface Supplier7Record {
+  id: string;
+  createdAt: Date;
+  updatedAt: Date;
+  tags: string[];
+  active: boolean;
+  name: string;
+}
+
+export enum Supplier7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier7Record>();
+  #status: Supplier7Status = Supplier7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier7Record]>): Supplier7Repository<T> {
+    const repository = new Supplier7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier7(records: readonly Supplier7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier7 = async (stream: Readable): Promise<Supplier7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier7Record[];
+};
+
+export interface Coupon7Record {
+  total: number;
+  id: string;
+  name: string;
+  updatedAt: Date;
+  createdAt: Date;
+  tags: string[];
+}
+
+export enum Coupon7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon7Record>();
+  #status: Coupon7Status = Coupon7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon7Record]>): Coupon7Repository<T> {
+    const repository = new Coupon7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon7(records: readonly Coupon7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon7 = async (stream: Readable): Promise<Coupon7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon7Record[];
+};
+
+export interface Refund7Record {
+  notes: string | undefined;
+  updatedAt: Date;
+  owner: string | null;
+  id: string;
+  name: string;
+  active: boolean;
+}
+
+export enum Refund7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund7Record>();
+  #status: Refund7Status = Refund7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund7Record]>): Refund7Repository<T> {
+    const repository = new Refund7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund7(records: readonly Refund7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund7 = async (stream: Readable): Promise<Refund7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund7Record[];
+};
+
+export interface Ticket7Record {
+  tags: string[];
+  updatedAt: Date;
+  total: number;
+  name: string;
+  id: string;
+  notes: string | undefined;
+}
+
+export enum Ticket7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket7Record>();
+  #status: Ticket7Status = Ticket7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket7Record]>): Ticket7Repository<T> {
+    const repository = new Ticket7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket7(records: readonly Ticket7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket7 = async (stream: Readable): Promise<Ticket7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket7Record[];
+};
+
+export interface Message7Record {
+  active: boolean;
+  total: number;
+  owner: string | null;
+  id: string;
+  createdAt: Date;
+  count: number;
+}
+
+export enum Message7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message7Record>();
+  #status: Message7Status = Message7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message7Record]>): Message7Repository<T> {
+    const repository = new Message7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage7(records: readonly Message7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage7 = async (stream: Readable): Promise<Message7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message7Record[];
+};
+
+export interface Channel7Record {
+  owner: string | null;
+  total: number;
+  id: string;
+  updatedAt: Date;
+  active: boolean;
+  name: string;
+}
+
+export enum Channel7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel7Record>();
+  #status: Channel7Status = Channel7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel7Record]>): Channel7Repository<T> {
+    const repository = new Channel7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel7(records: readonly Channel7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel7 = async (stream: Readable): Promise<Channel7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel7Record[];
+};
+
+export interface Report7Record {
+  name: string;
+  id: string;
+  tags: string[];
+  updatedAt: Date;
+  total: number;
+  notes: string | undefined;
+}
+
+export enum Report7Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report7Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report7Record>();
+  #status: Report7Status = Report7Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report7Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report7Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report7 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report7Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report7Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report7Record]>): Report7Repository<T> {
+    const repository = new Report7Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport7(records: readonly Report7Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport7 = async (stream: Readable): Promise<Report7Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report7Record[];
+};
+
+export namespace Batch7 {
+  export function size(): number {
+    return 7;
+  }
+}
+
+export interface User8Record {
+  owner: string | null;
+  id: string;
+  tags: string[];
+  count: number;
+  active: boolean;
+  createdAt: Date;
+}
+
+export enum User8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User8Record>();
+  #status: User8Status = User8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User8Record]>): User8Repository<T> {
+    const repository = new User8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser8(records: readonly User8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser8 = async (stream: Readable): Promise<User8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User8Record[];
+};
+
+export interface Order8Record {
+  tags: string[];
+  total: number;
+  createdAt: Date;
+  notes: string | undefined;
+  name: string;
+  id: string;
+}
+
+export enum Order8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order8Record>();
+  #status: Order8Status = Order8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order8Record]>): Order8Repository<T> {
+    const repository = new Order8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder8(records: readonly Order8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder8 = async (stream: Readable): Promise<Order8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order8Record[];
+};
+
+export interface Invoice8Record {
+  name: string;
+  count: number;
+  id: string;
+  total: number;
+  updatedAt: Date;
+  active: boolean;
+}
+
+export enum Invoice8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice8Record>();
+  #status: Invoice8Status = Invoice8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice8Record]>): Invoice8Repository<T> {
+    const repository = new Invoice8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice8(records: readonly Invoice8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice8 = async (stream: Readable): Promise<Invoice8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice8Record[];
+};
+
+export interface Product8Record {
+  name: string;
+  tags: string[];
+  count: number;
+  id: string;
+  total: number;
+  createdAt: Date;
+}
+
+export enum Product8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product8Record>();
+  #status: Product8Status = Product8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product8Record]>): Product8Repository<T> {
+    const repository = new Product8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct8(records: readonly Product8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct8 = async (stream: Readable): Promise<Product8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product8Record[];
+};
+
+export interface Session8Record {
+  count: number;
+  updatedAt: Date;
+  name: string;
+  owner: string | null;
+  total: number;
+  notes: string | undefined;
+}
+
+export enum Session8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session8Record>();
+  #status: Session8Status = Session8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session8Record]>): Session8Repository<T> {
+    const repository = new Session8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession8(records: readonly Session8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession8 = async (stream: Readable): Promise<Session8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session8Record[];
+};
+
+export interface Token8Record {
+  active: boolean;
+  owner: string | null;
+  createdAt: Date;
+  count: number;
+  tags: string[];
+  notes: string | undefined;
+}
+
+export enum Token8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token8Record>();
+  #status: Token8Status = Token8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token8Record]>): Token8Repository<T> {
+    const repository = new Token8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken8(records: readonly Token8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken8 = async (stream: Readable): Promise<Token8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token8Record[];
+};
+
+export interface Account8Record {
+  id: string;
+  notes: string | undefined;
+  owner: string | null;
+  tags: string[];
+  active: boolean;
+  updatedAt: Date;
+}
+
+export enum Account8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account8Record>();
+  #status: Account8Status = Account8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account8Record]>): Account8Repository<T> {
+    const repository = new Account8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount8(records: readonly Account8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount8 = async (stream: Readable): Promise<Account8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account8Record[];
+};
+
+export interface Cart8Record {
+  owner: string | null;
+  count: number;
+  active: boolean;
+  total: number;
+  id: string;
+  name: string;
+}
+
+export enum Cart8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart8Record>();
+  #status: Cart8Status = Cart8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart8Record]>): Cart8Repository<T> {
+    const repository = new Cart8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart8(records: readonly Cart8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart8 = async (stream: Readable): Promise<Cart8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart8Record[];
+};
+
+export interface Shipment8Record {
+  total: number;
+  name: string;
+  createdAt: Date;
+  id: string;
+  tags: string[];
+  updatedAt: Date;
+}
+
+export enum Shipment8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment8Record>();
+  #status: Shipment8Status = Shipment8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment8Record]>): Shipment8Repository<T> {
+    const repository = new Shipment8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment8(records: readonly Shipment8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment8 = async (stream: Readable): Promise<Shipment8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment8Record[];
+};
+
+export interface Payment8Record {
+  count: number;
+  name: string;
+  notes: string | undefined;
+  createdAt: Date;
+  owner: string | null;
+  id: string;
+}
+
+export enum Payment8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment8Record>();
+  #status: Payment8Status = Payment8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment8Record]>): Payment8Repository<T> {
+    const repository = new Payment8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment8(records: readonly Payment8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment8 = async (stream: Readable): Promise<Payment8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment8Record[];
+};
+
+export interface Review8Record {
+  active: boolean;
+  total: number;
+  id: string;
+  name: string;
+  count: number;
+  createdAt: Date;
+}
+
+export enum Review8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review8Record>();
+  #status: Review8Status = Review8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review8Record]>): Review8Repository<T> {
+    const repository = new Review8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview8(records: readonly Review8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview8 = async (stream: Readable): Promise<Review8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review8Record[];
+};
+
+export interface Category8Record {
+  total: number;
+  updatedAt: Date;
+  name: string;
+  createdAt: Date;
+  tags: string[];
+  id: string;
+}
+
+export enum Category8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category8Record>();
+  #status: Category8Status = Category8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category8Record]>): Category8Repository<T> {
+    const repository = new Category8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory8(records: readonly Category8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory8 = async (stream: Readable): Promise<Category8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category8Record[];
+};
+
+export interface Warehouse8Record {
+  updatedAt: Date;
+  active: boolean;
+  name: string;
+  owner: string | null;
+  createdAt: Date;
+  id: string;
+}
+
+export enum Warehouse8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse8Record>();
+  #status: Warehouse8Status = Warehouse8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse8Record]>): Warehouse8Repository<T> {
+    const repository = new Warehouse8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse8(records: readonly Warehouse8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse8 = async (stream: Readable): Promise<Warehouse8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse8Record[];
+};
+
+export interface Supplier8Record {
+  name: string;
+  id: string;
+  createdAt: Date;
+  count: number;
+  updatedAt: Date;
+  tags: string[];
+}
+
+export enum Supplier8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier8Record>();
+  #status: Supplier8Status = Supplier8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier8Record]>): Supplier8Repository<T> {
+    const repository = new Supplier8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier8(records: readonly Supplier8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier8 = async (stream: Readable): Promise<Supplier8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier8Record[];
+};
+
+export interface Coupon8Record {
+  tags: string[];
+  notes: string | undefined;
+  total: number;
+  createdAt: Date;
+  name: string;
+  count: number;
+}
+
+export enum Coupon8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon8Record>();
+  #status: Coupon8Status = Coupon8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon8Record]>): Coupon8Repository<T> {
+    const repository = new Coupon8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon8(records: readonly Coupon8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon8 = async (stream: Readable): Promise<Coupon8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon8Record[];
+};
+
+export interface Refund8Record {
+  updatedAt: Date;
+  createdAt: Date;
+  total: number;
+  tags: string[];
+  count: number;
+  owner: string | null;
+}
+
+export enum Refund8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund8Record>();
+  #status: Refund8Status = Refund8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund8Record]>): Refund8Repository<T> {
+    const repository = new Refund8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund8(records: readonly Refund8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund8 = async (stream: Readable): Promise<Refund8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund8Record[];
+};
+
+export interface Ticket8Record {
+  updatedAt: Date;
+  name: string;
+  owner: string | null;
+  count: number;
+  id: string;
+  createdAt: Date;
+}
+
+export enum Ticket8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket8Record>();
+  #status: Ticket8Status = Ticket8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket8Record]>): Ticket8Repository<T> {
+    const repository = new Ticket8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket8(records: readonly Ticket8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket8 = async (stream: Readable): Promise<Ticket8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket8Record[];
+};
+
+export interface Message8Record {
+  owner: string | null;
+  updatedAt: Date;
+  count: number;
+  name: string;
+  createdAt: Date;
+  id: string;
+}
+
+export enum Message8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message8Record>();
+  #status: Message8Status = Message8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message8Record]>): Message8Repository<T> {
+    const repository = new Message8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage8(records: readonly Message8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage8 = async (stream: Readable): Promise<Message8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message8Record[];
+};
+
+export interface Channel8Record {
+  tags: string[];
+  total: number;
+  createdAt: Date;
+  id: string;
+  count: number;
+  owner: string | null;
+}
+
+export enum Channel8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel8Record>();
+  #status: Channel8Status = Channel8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel8Record]>): Channel8Repository<T> {
+    const repository = new Channel8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel8(records: readonly Channel8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel8 = async (stream: Readable): Promise<Channel8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel8Record[];
+};
+
+export interface Report8Record {
+  total: number;
+  count: number;
+  updatedAt: Date;
+  active: boolean;
+  owner: string | null;
+  createdAt: Date;
+}
+
+export enum Report8Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report8Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report8Record>();
+  #status: Report8Status = Report8Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report8Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report8Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report8 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report8Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report8Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report8Record]>): Report8Repository<T> {
+    const repository = new Report8Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport8(records: readonly Report8Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport8 = async (stream: Readable): Promise<Report8Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report8Record[];
+};
+
+export namespace Batch8 {
+  export function size(): number {
+    return 8;
+  }
+}
+
+export interface User9Record {
+  id: string;
+  count: number;
+  updatedAt: Date;
+  notes: string | undefined;
+  active: boolean;
+  total: number;
+}
+
+export enum User9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User9Record>();
+  #status: User9Status = User9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User9Record]>): User9Repository<T> {
+    const repository = new User9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser9(records: readonly User9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser9 = async (stream: Readable): Promise<User9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User9Record[];
+};
+
+export interface Order9Record {
+  name: string;
+  updatedAt: Date;
+  count: number;
+  createdAt: Date;
+  notes: string | undefined;
+  owner: string | null;
+}
+
+export enum Order9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order9Record>();
+  #status: Order9Status = Order9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order9Record]>): Order9Repository<T> {
+    const repository = new Order9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder9(records: readonly Order9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder9 = async (stream: Readable): Promise<Order9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order9Record[];
+};
+
+export interface Invoice9Record {
+  tags: string[];
+  updatedAt: Date;
+  count: number;
+  notes: string | undefined;
+  createdAt: Date;
+  total: number;
+}
+
+export enum Invoice9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice9Record>();
+  #status: Invoice9Status = Invoice9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice9Record]>): Invoice9Repository<T> {
+    const repository = new Invoice9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice9(records: readonly Invoice9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice9 = async (stream: Readable): Promise<Invoice9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice9Record[];
+};
+
+export interface Product9Record {
+  count: number;
+  tags: string[];
+  total: number;
+  owner: string | null;
+  name: string;
+  notes: string | undefined;
+}
+
+export enum Product9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product9Record>();
+  #status: Product9Status = Product9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product9Record]>): Product9Repository<T> {
+    const repository = new Product9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct9(records: readonly Product9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct9 = async (stream: Readable): Promise<Product9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product9Record[];
+};
+
+export interface Session9Record {
+  tags: string[];
+  total: number;
+  active: boolean;
+  updatedAt: Date;
+  count: number;
+  createdAt: Date;
+}
+
+export enum Session9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session9Record>();
+  #status: Session9Status = Session9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session9Record]>): Session9Repository<T> {
+    const repository = new Session9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession9(records: readonly Session9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession9 = async (stream: Readable): Promise<Session9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session9Record[];
+};
+
+export interface Token9Record {
+  count: number;
+  owner: string | null;
+  active: boolean;
+  updatedAt: Date;
+  total: number;
+  tags: string[];
+}
+
+export enum Token9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token9Record>();
+  #status: Token9Status = Token9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token9Record]>): Token9Repository<T> {
+    const repository = new Token9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken9(records: readonly Token9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken9 = async (stream: Readable): Promise<Token9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token9Record[];
+};
+
+export interface Account9Record {
+  id: string;
+  owner: string | null;
+  total: number;
+  createdAt: Date;
+  updatedAt: Date;
+  active: boolean;
+}
+
+export enum Account9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account9Record>();
+  #status: Account9Status = Account9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account9Record]>): Account9Repository<T> {
+    const repository = new Account9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount9(records: readonly Account9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount9 = async (stream: Readable): Promise<Account9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account9Record[];
+};
+
+export interface Cart9Record {
+  count: number;
+  updatedAt: Date;
+  active: boolean;
+  createdAt: Date;
+  total: number;
+  id: string;
+}
+
+export enum Cart9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart9Record>();
+  #status: Cart9Status = Cart9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart9Record]>): Cart9Repository<T> {
+    const repository = new Cart9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart9(records: readonly Cart9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart9 = async (stream: Readable): Promise<Cart9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart9Record[];
+};
+
+export interface Shipment9Record {
+  count: number;
+  active: boolean;
+  id: string;
+  owner: string | null;
+  tags: string[];
+  name: string;
+}
+
+export enum Shipment9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment9Record>();
+  #status: Shipment9Status = Shipment9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment9Record]>): Shipment9Repository<T> {
+    const repository = new Shipment9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment9(records: readonly Shipment9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment9 = async (stream: Readable): Promise<Shipment9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment9Record[];
+};
+
+export interface Payment9Record {
+  updatedAt: Date;
+  active: boolean;
+  notes: string | undefined;
+  count: number;
+  owner: string | null;
+  tags: string[];
+}
+
+export enum Payment9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment9Record>();
+  #status: Payment9Status = Payment9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment9Record]>): Payment9Repository<T> {
+    const repository = new Payment9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment9(records: readonly Payment9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment9 = async (stream: Readable): Promise<Payment9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment9Record[];
+};
+
+export interface Review9Record {
+  total: number;
+  name: string;
+  owner: string | null;
+  id: string;
+  updatedAt: Date;
+  active: boolean;
+}
+
+export enum Review9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review9Record>();
+  #status: Review9Status = Review9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review9Record]>): Review9Repository<T> {
+    const repository = new Review9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview9(records: readonly Review9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview9 = async (stream: Readable): Promise<Review9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review9Record[];
+};
+
+export interface Category9Record {
+  count: number;
+  updatedAt: Date;
+  owner: string | null;
+  name: string;
+  createdAt: Date;
+  tags: string[];
+}
+
+export enum Category9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category9Record>();
+  #status: Category9Status = Category9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category9Record]>): Category9Repository<T> {
+    const repository = new Category9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory9(records: readonly Category9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory9 = async (stream: Readable): Promise<Category9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category9Record[];
+};
+
+export interface Warehouse9Record {
+  total: number;
+  updatedAt: Date;
+  count: number;
+  name: string;
+  active: boolean;
+  tags: string[];
+}
+
+export enum Warehouse9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse9Record>();
+  #status: Warehouse9Status = Warehouse9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse9Record]>): Warehouse9Repository<T> {
+    const repository = new Warehouse9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse9(records: readonly Warehouse9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse9 = async (stream: Readable): Promise<Warehouse9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse9Record[];
+};
+
+export interface Supplier9Record {
+  id: string;
+  owner: string | null;
+  notes: string | undefined;
+  updatedAt: Date;
+  tags: string[];
+  name: string;
+}
+
+export enum Supplier9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier9Record>();
+  #status: Supplier9Status = Supplier9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier9Record]>): Supplier9Repository<T> {
+    const repository = new Supplier9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier9(records: readonly Supplier9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier9 = async (stream: Readable): Promise<Supplier9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier9Record[];
+};
+
+export interface Coupon9Record {
+  tags: string[];
+  id: string;
+  owner: string | null;
+  total: number;
+  count: number;
+  active: boolean;
+}
+
+export enum Coupon9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon9Record>();
+  #status: Coupon9Status = Coupon9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon9Record]>): Coupon9Repository<T> {
+    const repository = new Coupon9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon9(records: readonly Coupon9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon9 = async (stream: Readable): Promise<Coupon9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon9Record[];
+};
+
+export interface Refund9Record {
+  notes: string | undefined;
+  active: boolean;
+  count: number;
+  total: number;
+  tags: string[];
+  name: string;
+}
+
+export enum Refund9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund9Record>();
+  #status: Refund9Status = Refund9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund9Record]>): Refund9Repository<T> {
+    const repository = new Refund9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund9(records: readonly Refund9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund9 = async (stream: Readable): Promise<Refund9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund9Record[];
+};
+
+export interface Ticket9Record {
+  createdAt: Date;
+  id: string;
+  count: number;
+  updatedAt: Date;
+  name: string;
+  total: number;
+}
+
+export enum Ticket9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket9Record>();
+  #status: Ticket9Status = Ticket9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket9Record]>): Ticket9Repository<T> {
+    const repository = new Ticket9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket9(records: readonly Ticket9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket9 = async (stream: Readable): Promise<Ticket9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket9Record[];
+};
+
+export interface Message9Record {
+  owner: string | null;
+  id: string;
+  notes: string | undefined;
+  name: string;
+  active: boolean;
+  createdAt: Date;
+}
+
+export enum Message9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message9Record>();
+  #status: Message9Status = Message9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message9Record]>): Message9Repository<T> {
+    const repository = new Message9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage9(records: readonly Message9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage9 = async (stream: Readable): Promise<Message9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message9Record[];
+};
+
+export interface Channel9Record {
+  notes: string | undefined;
+  total: number;
+  updatedAt: Date;
+  owner: string | null;
+  createdAt: Date;
+  id: string;
+}
+
+export enum Channel9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel9Record>();
+  #status: Channel9Status = Channel9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel9Record]>): Channel9Repository<T> {
+    const repository = new Channel9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel9(records: readonly Channel9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel9 = async (stream: Readable): Promise<Channel9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel9Record[];
+};
+
+export interface Report9Record {
+  createdAt: Date;
+  owner: string | null;
+  tags: string[];
+  id: string;
+  notes: string | undefined;
+  updatedAt: Date;
+}
+
+export enum Report9Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report9Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report9Record>();
+  #status: Report9Status = Report9Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report9Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report9Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report9 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report9Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report9Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report9Record]>): Report9Repository<T> {
+    const repository = new Report9Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport9(records: readonly Report9Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport9 = async (stream: Readable): Promise<Report9Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report9Record[];
+};
+
+export namespace Batch9 {
+  export function size(): number {
+    return 9;
+  }
+}
+
+export interface User10Record {
+  updatedAt: Date;
+  owner: string | null;
+  name: string;
+  createdAt: Date;
+  id: string;
+  active: boolean;
+}
+
+export enum User10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User10Record>();
+  #status: User10Status = User10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User10Record]>): User10Repository<T> {
+    const repository = new User10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser10(records: readonly User10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser10 = async (stream: Readable): Promise<User10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User10Record[];
+};
+
+export interface Order10Record {
+  notes: string | undefined;
+  active: boolean;
+  id: string;
+  count: number;
+  name: string;
+  updatedAt: Date;
+}
+
+export enum Order10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order10Record>();
+  #status: Order10Status = Order10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order10Record]>): Order10Repository<T> {
+    const repository = new Order10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder10(records: readonly Order10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder10 = async (stream: Readable): Promise<Order10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order10Record[];
+};
+
+export interface Invoice10Record {
+  notes: string | undefined;
+  updatedAt: Date;
+  id: string;
+  tags: string[];
+  total: number;
+  createdAt: Date;
+}
+
+export enum Invoice10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice10Record>();
+  #status: Invoice10Status = Invoice10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice10Record]>): Invoice10Repository<T> {
+    const repository = new Invoice10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice10(records: readonly Invoice10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice10 = async (stream: Readable): Promise<Invoice10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice10Record[];
+};
+
+export interface Product10Record {
+  id: string;
+  notes: string | undefined;
+  createdAt: Date;
+  count: number;
+  active: boolean;
+  tags: string[];
+}
+
+export enum Product10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product10Record>();
+  #status: Product10Status = Product10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product10Record]>): Product10Repository<T> {
+    const repository = new Product10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct10(records: readonly Product10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct10 = async (stream: Readable): Promise<Product10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product10Record[];
+};
+
+export interface Session10Record {
+  name: string;
+  owner: string | null;
+  updatedAt: Date;
+  tags: string[];
+  id: string;
+  count: number;
+}
+
+export enum Session10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session10Record>();
+  #status: Session10Status = Session10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session10Record]>): Session10Repository<T> {
+    const repository = new Session10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession10(records: readonly Session10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession10 = async (stream: Readable): Promise<Session10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session10Record[];
+};
+
+export interface Token10Record {
+  name: string;
+  notes: string | undefined;
+  owner: string | null;
+  tags: string[];
+  id: string;
+  count: number;
+}
+
+export enum Token10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token10Record>();
+  #status: Token10Status = Token10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token10Record]>): Token10Repository<T> {
+    const repository = new Token10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken10(records: readonly Token10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken10 = async (stream: Readable): Promise<Token10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token10Record[];
+};
+
+export interface Account10Record {
+  createdAt: Date;
+  name: string;
+  count: number;
+  total: number;
+  owner: string | null;
+  active: boolean;
+}
+
+export enum Account10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account10Record>();
+  #status: Account10Status = Account10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account10Record]>): Account10Repository<T> {
+    const repository = new Account10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount10(records: readonly Account10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount10 = async (stream: Readable): Promise<Account10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account10Record[];
+};
+
+export interface Cart10Record {
+  total: number;
+  id: string;
+  tags: string[];
+  updatedAt: Date;
+  name: string;
+  active: boolean;
+}
+
+export enum Cart10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart10Record>();
+  #status: Cart10Status = Cart10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart10Record]>): Cart10Repository<T> {
+    const repository = new Cart10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart10(records: readonly Cart10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart10 = async (stream: Readable): Promise<Cart10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart10Record[];
+};
+
+export interface Shipment10Record {
+  active: boolean;
+  name: string;
+  notes: string | undefined;
+  id: string;
+  owner: string | null;
+  createdAt: Date;
+}
+
+export enum Shipment10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment10Record>();
+  #status: Shipment10Status = Shipment10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment10Record]>): Shipment10Repository<T> {
+    const repository = new Shipment10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment10(records: readonly Shipment10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment10 = async (stream: Readable): Promise<Shipment10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment10Record[];
+};
+
+export interface Payment10Record {
+  tags: string[];
+  id: string;
+  owner: string | null;
+  count: number;
+  createdAt: Date;
+  name: string;
+}
+
+export enum Payment10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment10Record>();
+  #status: Payment10Status = Payment10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment10Record]>): Payment10Repository<T> {
+    const repository = new Payment10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment10(records: readonly Payment10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment10 = async (stream: Readable): Promise<Payment10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment10Record[];
+};
+
+export interface Review10Record {
+  active: boolean;
+  count: number;
+  updatedAt: Date;
+  createdAt: Date;
+  id: string;
+  owner: string | null;
+}
+
+export enum Review10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review10Record>();
+  #status: Review10Status = Review10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review10Record]>): Review10Repository<T> {
+    const repository = new Review10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview10(records: readonly Review10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview10 = async (stream: Readable): Promise<Review10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review10Record[];
+};
+
+export interface Category10Record {
+  owner: string | null;
+  total: number;
+  active: boolean;
+  name: string;
+  tags: string[];
+  count: number;
+}
+
+export enum Category10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category10Record>();
+  #status: Category10Status = Category10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category10Record]>): Category10Repository<T> {
+    const repository = new Category10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory10(records: readonly Category10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory10 = async (stream: Readable): Promise<Category10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category10Record[];
+};
+
+export interface Warehouse10Record {
+  createdAt: Date;
+  notes: string | undefined;
+  count: number;
+  owner: string | null;
+  name: string;
+  total: number;
+}
+
+export enum Warehouse10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse10Record>();
+  #status: Warehouse10Status = Warehouse10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse10Record]>): Warehouse10Repository<T> {
+    const repository = new Warehouse10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse10(records: readonly Warehouse10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse10 = async (stream: Readable): Promise<Warehouse10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse10Record[];
+};
+
+export interface Supplier10Record {
+  name: string;
+  tags: string[];
+  id: string;
+  updatedAt: Date;
+  active: boolean;
+  createdAt: Date;
+}
+
+export enum Supplier10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier10Record>();
+  #status: Supplier10Status = Supplier10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier10Record]>): Supplier10Repository<T> {
+    const repository = new Supplier10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier10(records: readonly Supplier10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier10 = async (stream: Readable): Promise<Supplier10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier10Record[];
+};
+
+export interface Coupon10Record {
+  createdAt: Date;
+  active: boolean;
+  notes: string | undefined;
+  updatedAt: Date;
+  name: string;
+  total: number;
+}
+
+export enum Coupon10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon10Record>();
+  #status: Coupon10Status = Coupon10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon10Record]>): Coupon10Repository<T> {
+    const repository = new Coupon10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon10(records: readonly Coupon10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon10 = async (stream: Readable): Promise<Coupon10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon10Record[];
+};
+
+export interface Refund10Record {
+  tags: string[];
+  name: string;
+  id: string;
+  count: number;
+  owner: string | null;
+  total: number;
+}
+
+export enum Refund10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund10Record>();
+  #status: Refund10Status = Refund10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund10Record]>): Refund10Repository<T> {
+    const repository = new Refund10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund10(records: readonly Refund10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund10 = async (stream: Readable): Promise<Refund10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund10Record[];
+};
+
+export interface Ticket10Record {
+  total: number;
+  owner: string | null;
+  updatedAt: Date;
+  createdAt: Date;
+  notes: string | undefined;
+  count: number;
+}
+
+export enum Ticket10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket10Record>();
+  #status: Ticket10Status = Ticket10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket10Record]>): Ticket10Repository<T> {
+    const repository = new Ticket10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket10(records: readonly Ticket10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket10 = async (stream: Readable): Promise<Ticket10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket10Record[];
+};
+
+export interface Message10Record {
+  total: number;
+  updatedAt: Date;
+  notes: string | undefined;
+  active: boolean;
+  count: number;
+  createdAt: Date;
+}
+
+export enum Message10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message10Record>();
+  #status: Message10Status = Message10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message10Record]>): Message10Repository<T> {
+    const repository = new Message10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage10(records: readonly Message10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage10 = async (stream: Readable): Promise<Message10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message10Record[];
+};
+
+export interface Channel10Record {
+  updatedAt: Date;
+  count: number;
+  active: boolean;
+  total: number;
+  notes: string | undefined;
+  id: string;
+}
+
+export enum Channel10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel10Record>();
+  #status: Channel10Status = Channel10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel10Record]>): Channel10Repository<T> {
+    const repository = new Channel10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel10(records: readonly Channel10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel10 = async (stream: Readable): Promise<Channel10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel10Record[];
+};
+
+export interface Report10Record {
+  total: number;
+  notes: string | undefined;
+  active: boolean;
+  name: string;
+  count: number;
+  tags: string[];
+}
+
+export enum Report10Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report10Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report10Record>();
+  #status: Report10Status = Report10Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report10Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report10Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report10 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report10Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report10Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report10Record]>): Report10Repository<T> {
+    const repository = new Report10Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport10(records: readonly Report10Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport10 = async (stream: Readable): Promise<Report10Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report10Record[];
+};
+
+export namespace Batch10 {
+  export function size(): number {
+    return 10;
+  }
+}
+
+export interface User11Record {
+  notes: string | undefined;
+  name: string;
+  tags: string[];
+  id: string;
+  updatedAt: Date;
+  createdAt: Date;
+}
+
+export enum User11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User11Record>();
+  #status: User11Status = User11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User11Record]>): User11Repository<T> {
+    const repository = new User11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser11(records: readonly User11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser11 = async (stream: Readable): Promise<User11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User11Record[];
+};
+
+export interface Order11Record {
+  name: string;
+  active: boolean;
+  createdAt: Date;
+  count: number;
+  tags: string[];
+  total: number;
+}
+
+export enum Order11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order11Record>();
+  #status: Order11Status = Order11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order11Record]>): Order11Repository<T> {
+    const repository = new Order11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder11(records: readonly Order11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder11 = async (stream: Readable): Promise<Order11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order11Record[];
+};
+
+export interface Invoice11Record {
+  createdAt: Date;
+  updatedAt: Date;
+  owner: string | null;
+  name: string;
+  total: number;
+  id: string;
+}
+
+export enum Invoice11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice11Record>();
+  #status: Invoice11Status = Invoice11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice11Record]>): Invoice11Repository<T> {
+    const repository = new Invoice11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice11(records: readonly Invoice11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice11 = async (stream: Readable): Promise<Invoice11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice11Record[];
+};
+
+export interface Product11Record {
+  updatedAt: Date;
+  notes: string | undefined;
+  active: boolean;
+  name: string;
+  createdAt: Date;
+  owner: string | null;
+}
+
+export enum Product11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product11Record>();
+  #status: Product11Status = Product11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product11Record]>): Product11Repository<T> {
+    const repository = new Product11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct11(records: readonly Product11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct11 = async (stream: Readable): Promise<Product11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product11Record[];
+};
+
+export interface Session11Record {
+  tags: string[];
+  owner: string | null;
+  active: boolean;
+  createdAt: Date;
+  count: number;
+  notes: string | undefined;
+}
+
+export enum Session11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session11Record>();
+  #status: Session11Status = Session11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session11Record]>): Session11Repository<T> {
+    const repository = new Session11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession11(records: readonly Session11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession11 = async (stream: Readable): Promise<Session11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session11Record[];
+};
+
+export interface Token11Record {
+  createdAt: Date;
+  count: number;
+  active: boolean;
+  owner: string | null;
+  updatedAt: Date;
+  tags: string[];
+}
+
+export enum Token11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token11Record>();
+  #status: Token11Status = Token11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token11Record]>): Token11Repository<T> {
+    const repository = new Token11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken11(records: readonly Token11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken11 = async (stream: Readable): Promise<Token11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token11Record[];
+};
+
+export interface Account11Record {
+  count: number;
+  total: number;
+  updatedAt: Date;
+  owner: string | null;
+  id: string;
+  active: boolean;
+}
+
+export enum Account11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account11Record>();
+  #status: Account11Status = Account11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account11Record]>): Account11Repository<T> {
+    const repository = new Account11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount11(records: readonly Account11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount11 = async (stream: Readable): Promise<Account11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account11Record[];
+};
+
+export interface Cart11Record {
+  total: number;
+  updatedAt: Date;
+  tags: string[];
+  owner: string | null;
+  id: string;
+  name: string;
+}
+
+export enum Cart11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart11Record>();
+  #status: Cart11Status = Cart11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart11Record]>): Cart11Repository<T> {
+    const repository = new Cart11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart11(records: readonly Cart11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart11 = async (stream: Readable): Promise<Cart11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart11Record[];
+};
+
+export interface Shipment11Record {
+  total: number;
+  active: boolean;
+  count: number;
+  id: string;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export enum Shipment11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment11Record>();
+  #status: Shipment11Status = Shipment11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment11Record]>): Shipment11Repository<T> {
+    const repository = new Shipment11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment11(records: readonly Shipment11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment11 = async (stream: Readable): Promise<Shipment11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment11Record[];
+};
+
+export interface Payment11Record {
+  name: string;
+  active: boolean;
+  notes: string | undefined;
+  id: string;
+  createdAt: Date;
+  count: number;
+}
+
+export enum Payment11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment11Record>();
+  #status: Payment11Status = Payment11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment11Record]>): Payment11Repository<T> {
+    const repository = new Payment11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment11(records: readonly Payment11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment11 = async (stream: Readable): Promise<Payment11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment11Record[];
+};
+
+export interface Review11Record {
+  total: number;
+  active: boolean;
+  owner: string | null;
+  count: number;
+  id: string;
+  updatedAt: Date;
+}
+
+export enum Review11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review11Record>();
+  #status: Review11Status = Review11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review11Record]>): Review11Repository<T> {
+    const repository = new Review11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview11(records: readonly Review11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview11 = async (stream: Readable): Promise<Review11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review11Record[];
+};
+
+export interface Category11Record {
+  createdAt: Date;
+  total: number;
+  name: string;
+  updatedAt: Date;
+  id: string;
+  notes: string | undefined;
+}
+
+export enum Category11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category11Record>();
+  #status: Category11Status = Category11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category11Record]>): Category11Repository<T> {
+    const repository = new Category11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory11(records: readonly Category11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory11 = async (stream: Readable): Promise<Category11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category11Record[];
+};
+
+export interface Warehouse11Record {
+  updatedAt: Date;
+  total: number;
+  notes: string | undefined;
+  owner: string | null;
+  createdAt: Date;
+  id: string;
+}
+
+export enum Warehouse11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse11Record>();
+  #status: Warehouse11Status = Warehouse11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse11Record]>): Warehouse11Repository<T> {
+    const repository = new Warehouse11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse11(records: readonly Warehouse11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse11 = async (stream: Readable): Promise<Warehouse11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse11Record[];
+};
+
+export interface Supplier11Record {
+  id: string;
+  updatedAt: Date;
+  createdAt: Date;
+  owner: string | null;
+  notes: string | undefined;
+  name: string;
+}
+
+export enum Supplier11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier11Record>();
+  #status: Supplier11Status = Supplier11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier11Record]>): Supplier11Repository<T> {
+    const repository = new Supplier11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier11(records: readonly Supplier11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier11 = async (stream: Readable): Promise<Supplier11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier11Record[];
+};
+
+export interface Coupon11Record {
+  notes: string | undefined;
+  updatedAt: Date;
+  total: number;
+  tags: string[];
+  createdAt: Date;
+  owner: string | null;
+}
+
+export enum Coupon11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon11Record>();
+  #status: Coupon11Status = Coupon11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon11Record]>): Coupon11Repository<T> {
+    const repository = new Coupon11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon11(records: readonly Coupon11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon11 = async (stream: Readable): Promise<Coupon11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon11Record[];
+};
+
+export interface Refund11Record {
+  createdAt: Date;
+  name: string;
+  owner: string | null;
+  notes: string | undefined;
+  id: string;
+  count: number;
+}
+
+export enum Refund11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund11Record>();
+  #status: Refund11Status = Refund11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund11Record]>): Refund11Repository<T> {
+    const repository = new Refund11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund11(records: readonly Refund11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund11 = async (stream: Readable): Promise<Refund11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund11Record[];
+};
+
+export interface Ticket11Record {
+  tags: string[];
+  notes: string | undefined;
+  createdAt: Date;
+  updatedAt: Date;
+  total: number;
+  owner: string | null;
+}
+
+export enum Ticket11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket11Record>();
+  #status: Ticket11Status = Ticket11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket11Record]>): Ticket11Repository<T> {
+    const repository = new Ticket11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket11(records: readonly Ticket11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket11 = async (stream: Readable): Promise<Ticket11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket11Record[];
+};
+
+export interface Message11Record {
+  notes: string | undefined;
+  updatedAt: Date;
+  tags: string[];
+  owner: string | null;
+  active: boolean;
+  createdAt: Date;
+}
+
+export enum Message11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message11Record>();
+  #status: Message11Status = Message11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message11Record]>): Message11Repository<T> {
+    const repository = new Message11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage11(records: readonly Message11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage11 = async (stream: Readable): Promise<Message11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message11Record[];
+};
+
+export interface Channel11Record {
+  id: string;
+  updatedAt: Date;
+  createdAt: Date;
+  name: string;
+  owner: string | null;
+  count: number;
+}
+
+export enum Channel11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel11Record>();
+  #status: Channel11Status = Channel11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel11Record]>): Channel11Repository<T> {
+    const repository = new Channel11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel11(records: readonly Channel11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel11 = async (stream: Readable): Promise<Channel11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel11Record[];
+};
+
+export interface Report11Record {
+  active: boolean;
+  count: number;
+  updatedAt: Date;
+  createdAt: Date;
+  id: string;
+  name: string;
+}
+
+export enum Report11Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report11Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report11Record>();
+  #status: Report11Status = Report11Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report11Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report11Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report11 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report11Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report11Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report11Record]>): Report11Repository<T> {
+    const repository = new Report11Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport11(records: readonly Report11Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport11 = async (stream: Readable): Promise<Report11Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report11Record[];
+};
+
+export namespace Batch11 {
+  export function size(): number {
+    return 11;
+  }
+}
+
+export interface User12Record {
+  id: string;
+  createdAt: Date;
+  active: boolean;
+  total: number;
+  updatedAt: Date;
+  count: number;
+}
+
+export enum User12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User12Record>();
+  #status: User12Status = User12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User12Record]>): User12Repository<T> {
+    const repository = new User12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser12(records: readonly User12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser12 = async (stream: Readable): Promise<User12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User12Record[];
+};
+
+export interface Order12Record {
+  count: number;
+  updatedAt: Date;
+  id: string;
+  total: number;
+  notes: string | undefined;
+  tags: string[];
+}
+
+export enum Order12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order12Record>();
+  #status: Order12Status = Order12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order12Record]>): Order12Repository<T> {
+    const repository = new Order12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder12(records: readonly Order12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder12 = async (stream: Readable): Promise<Order12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order12Record[];
+};
+
+export interface Invoice12Record {
+  count: number;
+  total: number;
+  owner: string | null;
+  tags: string[];
+  id: string;
+  updatedAt: Date;
+}
+
+export enum Invoice12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice12Record>();
+  #status: Invoice12Status = Invoice12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice12Record]>): Invoice12Repository<T> {
+    const repository = new Invoice12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice12(records: readonly Invoice12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice12 = async (stream: Readable): Promise<Invoice12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice12Record[];
+};
+
+export interface Product12Record {
+  tags: string[];
+  createdAt: Date;
+  count: number;
+  active: boolean;
+  updatedAt: Date;
+  total: number;
+}
+
+export enum Product12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product12Record>();
+  #status: Product12Status = Product12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product12Record]>): Product12Repository<T> {
+    const repository = new Product12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct12(records: readonly Product12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct12 = async (stream: Readable): Promise<Product12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product12Record[];
+};
+
+export interface Session12Record {
+  name: string;
+  id: string;
+  updatedAt: Date;
+  owner: string | null;
+  total: number;
+  active: boolean;
+}
+
+export enum Session12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session12Record>();
+  #status: Session12Status = Session12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session12Record]>): Session12Repository<T> {
+    const repository = new Session12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession12(records: readonly Session12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession12 = async (stream: Readable): Promise<Session12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session12Record[];
+};
+
+export interface Token12Record {
+  id: string;
+  active: boolean;
+  updatedAt: Date;
+  createdAt: Date;
+  owner: string | null;
+  count: number;
+}
+
+export enum Token12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token12Record>();
+  #status: Token12Status = Token12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token12Record]>): Token12Repository<T> {
+    const repository = new Token12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken12(records: readonly Token12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken12 = async (stream: Readable): Promise<Token12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token12Record[];
+};
+
+export interface Account12Record {
+  active: boolean;
+  createdAt: Date;
+  total: number;
+  count: number;
+  owner: string | null;
+  updatedAt: Date;
+}
+
+export enum Account12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account12Record>();
+  #status: Account12Status = Account12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account12Record]>): Account12Repository<T> {
+    const repository = new Account12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount12(records: readonly Account12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount12 = async (stream: Readable): Promise<Account12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account12Record[];
+};
+
+export interface Cart12Record {
+  active: boolean;
+  tags: string[];
+  owner: string | null;
+  name: string;
+  createdAt: Date;
+  id: string;
+}
+
+export enum Cart12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart12Record>();
+  #status: Cart12Status = Cart12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart12Record]>): Cart12Repository<T> {
+    const repository = new Cart12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart12(records: readonly Cart12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart12 = async (stream: Readable): Promise<Cart12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart12Record[];
+};
+
+export interface Shipment12Record {
+  name: string;
+  createdAt: Date;
+  id: string;
+  count: number;
+  tags: string[];
+  active: boolean;
+}
+
+export enum Shipment12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment12Record>();
+  #status: Shipment12Status = Shipment12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment12Record]>): Shipment12Repository<T> {
+    const repository = new Shipment12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment12(records: readonly Shipment12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment12 = async (stream: Readable): Promise<Shipment12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment12Record[];
+};
+
+export interface Payment12Record {
+  active: boolean;
+  tags: string[];
+  createdAt: Date;
+  owner: string | null;
+  notes: string | undefined;
+  total: number;
+}
+
+export enum Payment12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment12Record>();
+  #status: Payment12Status = Payment12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment12Record]>): Payment12Repository<T> {
+    const repository = new Payment12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment12(records: readonly Payment12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment12 = async (stream: Readable): Promise<Payment12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment12Record[];
+};
+
+export interface Review12Record {
+  total: number;
+  createdAt: Date;
+  updatedAt: Date;
+  active: boolean;
+  notes: string | undefined;
+  name: string;
+}
+
+export enum Review12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review12Record>();
+  #status: Review12Status = Review12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review12Record]>): Review12Repository<T> {
+    const repository = new Review12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview12(records: readonly Review12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview12 = async (stream: Readable): Promise<Review12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review12Record[];
+};
+
+export interface Category12Record {
+  notes: string | undefined;
+  id: string;
+  updatedAt: Date;
+  owner: string | null;
+  total: number;
+  active: boolean;
+}
+
+export enum Category12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category12Record>();
+  #status: Category12Status = Category12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category12Record]>): Category12Repository<T> {
+    const repository = new Category12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory12(records: readonly Category12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory12 = async (stream: Readable): Promise<Category12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category12Record[];
+};
+
+export interface Warehouse12Record {
+  name: string;
+  createdAt: Date;
+  notes: string | undefined;
+  tags: string[];
+  count: number;
+  updatedAt: Date;
+}
+
+export enum Warehouse12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse12Record>();
+  #status: Warehouse12Status = Warehouse12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse12Record]>): Warehouse12Repository<T> {
+    const repository = new Warehouse12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse12(records: readonly Warehouse12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse12 = async (stream: Readable): Promise<Warehouse12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse12Record[];
+};
+
+export interface Supplier12Record {
+  updatedAt: Date;
+  id: string;
+  active: boolean;
+  total: number;
+  name: string;
+  notes: string | undefined;
+}
+
+export enum Supplier12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier12Record>();
+  #status: Supplier12Status = Supplier12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier12Record]>): Supplier12Repository<T> {
+    const repository = new Supplier12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier12(records: readonly Supplier12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier12 = async (stream: Readable): Promise<Supplier12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier12Record[];
+};
+
+export interface Coupon12Record {
+  id: string;
+  updatedAt: Date;
+  tags: string[];
+  createdAt: Date;
+  active: boolean;
+  name: string;
+}
+
+export enum Coupon12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon12Record>();
+  #status: Coupon12Status = Coupon12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon12Record]>): Coupon12Repository<T> {
+    const repository = new Coupon12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon12(records: readonly Coupon12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon12 = async (stream: Readable): Promise<Coupon12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon12Record[];
+};
+
+export interface Refund12Record {
+  createdAt: Date;
+  name: string;
+  count: number;
+  id: string;
+  active: boolean;
+  owner: string | null;
+}
+
+export enum Refund12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund12Record>();
+  #status: Refund12Status = Refund12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund12Record]>): Refund12Repository<T> {
+    const repository = new Refund12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund12(records: readonly Refund12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund12 = async (stream: Readable): Promise<Refund12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund12Record[];
+};
+
+export interface Ticket12Record {
+  name: string;
+  active: boolean;
+  updatedAt: Date;
+  id: string;
+  notes: string | undefined;
+  count: number;
+}
+
+export enum Ticket12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket12Record>();
+  #status: Ticket12Status = Ticket12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket12Record]>): Ticket12Repository<T> {
+    const repository = new Ticket12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket12(records: readonly Ticket12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket12 = async (stream: Readable): Promise<Ticket12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket12Record[];
+};
+
+export interface Message12Record {
+  tags: string[];
+  notes: string | undefined;
+  total: number;
+  owner: string | null;
+  count: number;
+  id: string;
+}
+
+export enum Message12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message12Record>();
+  #status: Message12Status = Message12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message12Record]>): Message12Repository<T> {
+    const repository = new Message12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage12(records: readonly Message12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.tags ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage12 = async (stream: Readable): Promise<Message12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message12Record[];
+};
+
+export interface Channel12Record {
+  notes: string | undefined;
+  tags: string[];
+  count: number;
+  createdAt: Date;
+  id: string;
+  owner: string | null;
+}
+
+export enum Channel12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel12Record>();
+  #status: Channel12Status = Channel12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel12Record]>): Channel12Repository<T> {
+    const repository = new Channel12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel12(records: readonly Channel12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel12 = async (stream: Readable): Promise<Channel12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel12Record[];
+};
+
+export interface Report12Record {
+  count: number;
+  tags: string[];
+  notes: string | undefined;
+  updatedAt: Date;
+  total: number;
+  createdAt: Date;
+}
+
+export enum Report12Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report12Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report12Record>();
+  #status: Report12Status = Report12Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report12Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report12Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report12 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report12Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report12Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report12Record]>): Report12Repository<T> {
+    const repository = new Report12Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport12(records: readonly Report12Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport12 = async (stream: Readable): Promise<Report12Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report12Record[];
+};
+
+export namespace Batch12 {
+  export function size(): number {
+    return 12;
+  }
+}
+
+export interface User13Record {
+  count: number;
+  id: string;
+  active: boolean;
+  owner: string | null;
+  name: string;
+  total: number;
+}
+
+export enum User13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class User13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, User13Record>();
+  #status: User13Status = User13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): User13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<User13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing User13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: User13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, User13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, User13Record]>): User13Repository<T> {
+    const repository = new User13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeUser13(records: readonly User13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.count ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseUser13 = async (stream: Readable): Promise<User13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as User13Record[];
+};
+
+export interface Order13Record {
+  updatedAt: Date;
+  createdAt: Date;
+  name: string;
+  count: number;
+  owner: string | null;
+  active: boolean;
+}
+
+export enum Order13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Order13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Order13Record>();
+  #status: Order13Status = Order13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Order13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Order13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Order13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Order13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Order13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Order13Record]>): Order13Repository<T> {
+    const repository = new Order13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeOrder13(records: readonly Order13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseOrder13 = async (stream: Readable): Promise<Order13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Order13Record[];
+};
+
+export interface Invoice13Record {
+  updatedAt: Date;
+  total: number;
+  createdAt: Date;
+  owner: string | null;
+  tags: string[];
+  notes: string | undefined;
+}
+
+export enum Invoice13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Invoice13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Invoice13Record>();
+  #status: Invoice13Status = Invoice13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Invoice13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Invoice13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Invoice13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Invoice13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Invoice13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Invoice13Record]>): Invoice13Repository<T> {
+    const repository = new Invoice13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeInvoice13(records: readonly Invoice13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.updatedAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseInvoice13 = async (stream: Readable): Promise<Invoice13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Invoice13Record[];
+};
+
+export interface Product13Record {
+  id: string;
+  tags: string[];
+  createdAt: Date;
+  updatedAt: Date;
+  count: number;
+  active: boolean;
+}
+
+export enum Product13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Product13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Product13Record>();
+  #status: Product13Status = Product13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Product13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Product13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Product13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Product13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Product13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Product13Record]>): Product13Repository<T> {
+    const repository = new Product13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeProduct13(records: readonly Product13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.id ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseProduct13 = async (stream: Readable): Promise<Product13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Product13Record[];
+};
+
+export interface Session13Record {
+  createdAt: Date;
+  name: string;
+  count: number;
+  notes: string | undefined;
+  total: number;
+  active: boolean;
+}
+
+export enum Session13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Session13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Session13Record>();
+  #status: Session13Status = Session13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Session13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Session13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Session13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Session13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Session13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Session13Record]>): Session13Repository<T> {
+    const repository = new Session13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSession13(records: readonly Session13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSession13 = async (stream: Readable): Promise<Session13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Session13Record[];
+};
+
+export interface Token13Record {
+  notes: string | undefined;
+  id: string;
+  count: number;
+  tags: string[];
+  name: string;
+  active: boolean;
+}
+
+export enum Token13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Token13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Token13Record>();
+  #status: Token13Status = Token13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Token13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Token13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Token13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Token13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Token13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Token13Record]>): Token13Repository<T> {
+    const repository = new Token13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeToken13(records: readonly Token13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseToken13 = async (stream: Readable): Promise<Token13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Token13Record[];
+};
+
+export interface Account13Record {
+  createdAt: Date;
+  updatedAt: Date;
+  total: number;
+  name: string;
+  owner: string | null;
+  id: string;
+}
+
+export enum Account13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Account13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Account13Record>();
+  #status: Account13Status = Account13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Account13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Account13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Account13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Account13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Account13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Account13Record]>): Account13Repository<T> {
+    const repository = new Account13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeAccount13(records: readonly Account13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseAccount13 = async (stream: Readable): Promise<Account13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Account13Record[];
+};
+
+export interface Cart13Record {
+  createdAt: Date;
+  tags: string[];
+  total: number;
+  notes: string | undefined;
+  name: string;
+  active: boolean;
+}
+
+export enum Cart13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Cart13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Cart13Record>();
+  #status: Cart13Status = Cart13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Cart13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Cart13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Cart13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Cart13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Cart13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Cart13Record]>): Cart13Repository<T> {
+    const repository = new Cart13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCart13(records: readonly Cart13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCart13 = async (stream: Readable): Promise<Cart13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Cart13Record[];
+};
+
+export interface Shipment13Record {
+  active: boolean;
+  notes: string | undefined;
+  name: string;
+  createdAt: Date;
+  owner: string | null;
+  total: number;
+}
+
+export enum Shipment13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Shipment13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Shipment13Record>();
+  #status: Shipment13Status = Shipment13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Shipment13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Shipment13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Shipment13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Shipment13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Shipment13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Shipment13Record]>): Shipment13Repository<T> {
+    const repository = new Shipment13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeShipment13(records: readonly Shipment13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.active ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseShipment13 = async (stream: Readable): Promise<Shipment13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Shipment13Record[];
+};
+
+export interface Payment13Record {
+  createdAt: Date;
+  tags: string[];
+  owner: string | null;
+  active: boolean;
+  total: number;
+  id: string;
+}
+
+export enum Payment13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Payment13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Payment13Record>();
+  #status: Payment13Status = Payment13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Payment13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Payment13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Payment13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Payment13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Payment13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Payment13Record]>): Payment13Repository<T> {
+    const repository = new Payment13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizePayment13(records: readonly Payment13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.createdAt ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parsePayment13 = async (stream: Readable): Promise<Payment13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Payment13Record[];
+};
+
+export interface Review13Record {
+  notes: string | undefined;
+  name: string;
+  active: boolean;
+  count: number;
+  total: number;
+  id: string;
+}
+
+export enum Review13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Review13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Review13Record>();
+  #status: Review13Status = Review13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Review13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Review13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Review13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Review13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Review13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Review13Record]>): Review13Repository<T> {
+    const repository = new Review13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReview13(records: readonly Review13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.notes ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReview13 = async (stream: Readable): Promise<Review13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Review13Record[];
+};
+
+export interface Category13Record {
+  total: number;
+  owner: string | null;
+  id: string;
+  active: boolean;
+  updatedAt: Date;
+  tags: string[];
+}
+
+export enum Category13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Category13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Category13Record>();
+  #status: Category13Status = Category13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Category13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Category13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Category13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Category13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Category13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Category13Record]>): Category13Repository<T> {
+    const repository = new Category13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCategory13(records: readonly Category13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCategory13 = async (stream: Readable): Promise<Category13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Category13Record[];
+};
+
+export interface Warehouse13Record {
+  owner: string | null;
+  tags: string[];
+  active: boolean;
+  total: number;
+  name: string;
+  count: number;
+}
+
+export enum Warehouse13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Warehouse13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Warehouse13Record>();
+  #status: Warehouse13Status = Warehouse13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Warehouse13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Warehouse13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Warehouse13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Warehouse13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Warehouse13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Warehouse13Record]>): Warehouse13Repository<T> {
+    const repository = new Warehouse13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeWarehouse13(records: readonly Warehouse13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseWarehouse13 = async (stream: Readable): Promise<Warehouse13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Warehouse13Record[];
+};
+
+export interface Supplier13Record {
+  name: string;
+  tags: string[];
+  active: boolean;
+  notes: string | undefined;
+  owner: string | null;
+  updatedAt: Date;
+}
+
+export enum Supplier13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Supplier13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Supplier13Record>();
+  #status: Supplier13Status = Supplier13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Supplier13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Supplier13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Supplier13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Supplier13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Supplier13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Supplier13Record]>): Supplier13Repository<T> {
+    const repository = new Supplier13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeSupplier13(records: readonly Supplier13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseSupplier13 = async (stream: Readable): Promise<Supplier13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Supplier13Record[];
+};
+
+export interface Coupon13Record {
+  name: string;
+  createdAt: Date;
+  updatedAt: Date;
+  owner: string | null;
+  count: number;
+  tags: string[];
+}
+
+export enum Coupon13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Coupon13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Coupon13Record>();
+  #status: Coupon13Status = Coupon13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Coupon13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Coupon13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Coupon13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Coupon13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Coupon13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Coupon13Record]>): Coupon13Repository<T> {
+    const repository = new Coupon13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeCoupon13(records: readonly Coupon13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.name ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseCoupon13 = async (stream: Readable): Promise<Coupon13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Coupon13Record[];
+};
+
+export interface Refund13Record {
+  owner: string | null;
+  count: number;
+  total: number;
+  updatedAt: Date;
+  tags: string[];
+  active: boolean;
+}
+
+export enum Refund13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Refund13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Refund13Record>();
+  #status: Refund13Status = Refund13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Refund13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Refund13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Refund13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Refund13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Refund13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Refund13Record]>): Refund13Repository<T> {
+    const repository = new Refund13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeRefund13(records: readonly Refund13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.owner ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseRefund13 = async (stream: Readable): Promise<Refund13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Refund13Record[];
+};
+
+export interface Ticket13Record {
+  total: number;
+  updatedAt: Date;
+  notes: string | undefined;
+  tags: string[];
+  count: number;
+  name: string;
+}
+
+export enum Ticket13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Ticket13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Ticket13Record>();
+  #status: Ticket13Status = Ticket13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Ticket13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Ticket13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Ticket13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Ticket13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Ticket13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Ticket13Record]>): Ticket13Repository<T> {
+    const repository = new Ticket13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeTicket13(records: readonly Ticket13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseTicket13 = async (stream: Readable): Promise<Ticket13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Ticket13Record[];
+};
+
+export interface Message13Record {
+  total: number;
+  notes: string | undefined;
+  name: string;
+  owner: string | null;
+  updatedAt: Date;
+  count: number;
+}
+
+export enum Message13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Message13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Message13Record>();
+  #status: Message13Status = Message13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Message13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Message13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Message13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Message13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Message13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Message13Record]>): Message13Repository<T> {
+    const repository = new Message13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeMessage13(records: readonly Message13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseMessage13 = async (stream: Readable): Promise<Message13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Message13Record[];
+};
+
+export interface Channel13Record {
+  total: number;
+  owner: string | null;
+  createdAt: Date;
+  tags: string[];
+  active: boolean;
+  updatedAt: Date;
+}
+
+export enum Channel13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Channel13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Channel13Record>();
+  #status: Channel13Status = Channel13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Channel13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Channel13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Channel13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Channel13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Channel13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Channel13Record]>): Channel13Repository<T> {
+    const repository = new Channel13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeChannel13(records: readonly Channel13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseChannel13 = async (stream: Readable): Promise<Channel13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Channel13Record[];
+};
+
+export interface Report13Record {
+  total: number;
+  name: string;
+  createdAt: Date;
+  id: string;
+  count: number;
+  owner: string | null;
+}
+
+export enum Report13Status {
+  Pending = "pending",
+  Active = "active",
+  Archived = "archived",
+}
+
+export class Report13Repository<K extends string = string> extends EventEmitter {
+  private readonly items = new Map<K, Report13Record>();
+  #status: Report13Status = Report13Status.Pending;
+
+  constructor(private readonly root: string) {
+    super();
+  }
+
+  get status(): Report13Status {
+    return this.#status;
+  }
+
+  async find(key: K): Promise<Result<Report13Record>> {
+    const item = this.items.get(key);
+    if (!item) {
+      return { ok: false, error: new Error(`missing Report13 ${key}`) };
+    }
+    return { ok: true, value: item };
+  }
+
+  save(key: K, record: Report13Record): void {
+    this.items.set(key, record);
+    this.emit("saved", path.join(this.root, key));
+  }
+
+  *entries(): IterableIterator<[K, Report13Record]> {
+    for (const [key, value] of this.items) {
+      yield [key, value];
+    }
+  }
+
+  static from<T extends string>(root: string, records: Array<[T, Report13Record]>): Report13Repository<T> {
+    const repository = new Report13Repository<T>(root);
+    records.forEach(([key, record]) => repository.save(key, record));
+    return repository;
+  }
+}
+
+export function summarizeReport13(records: readonly Report13Record[]): Map<string, number> {
+  const summary = new Map<string, number>();
+  for (const record of records) {
+    const key = String(record.total ?? "unknown");
+    summary.set(key, (summary.get(key) ?? 0) + 1);
+  }
+  return summary;
+}
+
+export const parseReport13 = async (stream: Readable): Promise<Report13Record[]> => {
+  const chunks: Buffer[] = [];
+  for await (const chunk of stream) {
+    chunks.push(chunk as Buffer);
+  }
+  return JSON.parse(Buffer.concat(chunks).toString("utf8")) as Report13Record[];
+};
+
+export namespace Batch13 {
+  export function size(): number {
+    return 13;
+  }
+}
+
@@ -0,0 +1,21 @@
+#![no_main]
+
+use aiply::CodeParsingContext;
+use libfuzzer_sys::fuzz_target;
+use std::cell::RefCell;
+
+thread_local! {
+    static CONTEXT: RefCell<CodeParsingContext> = RefCell::new(CodeParsingContext::new("rust"));
+}
+
+// The input is a source file and a model response separated by a NUL byte.
+// Everything is collapsed, so the response exercises all markers.
+fuzz_target!(|data: &str| {
+    let (source, response) = data.split_once('\0').unwrap_or((data, data));
+    CONTEXT.with_borrow_mut(|ctx| {
+        let doc = ctx.collapse_unrelated_symbols(source, vec![]);
+        let collapsed = doc.collapsed_document();
+        doc.uncollapse_document(&collapsed);
+        doc.uncollapse_document(response);
+    });
+});
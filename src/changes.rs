@@ -0,0 +1,286 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use similar::{capture_diff_slices, Algorithm, DiffOp};
+use ureq::{json, serde_json::Value};
+
+use crate::scope::occurrences;
+use crate::{CodeParsingContext, Symbol, SymbolWithRange};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+    /// Unchanged, but placed elsewhere among its siblings.
+    Moved,
+}
+
+impl ChangeKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Modified => "modified",
+            ChangeKind::Moved => "moved",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SymbolChange {
+    pub kind: ChangeKind,
+    pub symbol: Symbol,
+    /// The symbol as written in the source, e.g. `impl FsState::insert`.
+    pub label: String,
+}
+
+impl fmt::Display for SymbolChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.kind.as_str(), self.label)
+    }
+}
+
+/// The changes as a JSON array of `{"change", "symbol", "label"}` objects.
+pub fn changes_to_json(changes: &[SymbolChange]) -> Value {
+    changes
+        .iter()
+        .map(|change| {
+            json!({
+                "change": change.kind.as_str(),
+                "symbol": change.symbol.to_string(),
+                "label": change.label,
+            })
+        })
+        .collect()
+}
+
+/// The symbols of one version of the document.
+pub(crate) struct Version<'a> {
+    pub(crate) code: &'a str,
+    pub(crate) symbols: Vec<SymbolWithRange>,
+}
+
+impl Version<'_> {
+    /// Text of the symbol without its nested symbols, so that a change to a
+    /// method does not also count as a change to its `impl`.
+    pub(crate) fn own_text(&self, index: usize) -> String {
+        let symbol = &self.symbols[index];
+        let mut text = String::new();
+        let mut pos = symbol.range.start;
+        for child in &self.symbols[index + 1..] {
+            if child.range.start >= symbol.range.end {
+                break;
+            }
+            if child.symbol.parts.len() == symbol.symbol.parts.len() + 1 && child.range.start >= pos
+            {
+                text.push_str(&self.code[pos..child.range.start]);
+                pos = child.range.end;
+            }
+        }
+        text.push_str(&self.code[pos..symbol.range.end]);
+        text
+    }
+
+    /// The summary of the outermost enclosing symbol followed by the rest of
+    /// the path, e.g. `impl FsState::insert`.
+    fn label(&self, index: usize) -> String {
+        let symbol = &self.symbols[index];
+        let outermost = self.symbols[..=index]
+            .iter()
+            .rev()
+            .find(|s| s.symbol.parts.len() == 1 && s.range.start <= symbol.range.start)
+            .unwrap_or(symbol);
+        let summary = self.code[outermost.summary_range.clone()]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let rest = &symbol.symbol.parts[outermost.symbol.parts.len()..];
+        if rest.is_empty() {
+            summary
+        } else {
+            format!("{summary}::{}", rest.join("::"))
+        }
+    }
+
+    /// Whether an enclosing symbol of the one at `index` is in `reported`.
+    fn inside(&self, index: usize, reported: &HashSet<usize>) -> bool {
+        let range = &self.symbols[index].range;
+        reported.iter().any(|&other| {
+            let outer = &self.symbols[other].range;
+            other != index && outer.start <= range.start && range.end <= outer.end
+        })
+    }
+}
+
+/// Indices of the symbols kept by both versions that changed place among
+/// their siblings. Only those the diff has to move are reported, so swapping
+/// two functions reports one of them.
+fn moved<'a>(
+    original_keys: &[(&'a Symbol, usize)],
+    edited_keys: &[(&'a Symbol, usize)],
+    common: &HashSet<(&Symbol, usize)>,
+) -> HashSet<usize> {
+    type Siblings<'a> = (
+        Vec<(&'a Symbol, usize)>,
+        Vec<(&'a Symbol, usize)>,
+        Vec<usize>,
+    );
+    let mut siblings: HashMap<&[String], Siblings> = HashMap::new();
+    let parent = |symbol: &'a Symbol| &symbol.parts[..symbol.parts.len() - 1];
+    for key in original_keys.iter().filter(|key| common.contains(key)) {
+        siblings.entry(parent(key.0)).or_default().0.push(*key);
+    }
+    for (index, key) in edited_keys.iter().enumerate() {
+        if common.contains(key) {
+            let entry = siblings.entry(parent(key.0)).or_default();
+            entry.1.push(*key);
+            entry.2.push(index);
+        }
+    }
+
+    let mut moved = HashSet::new();
+    for (original, edited, indices) in siblings.values() {
+        for op in capture_diff_slices(Algorithm::Myers, original, edited) {
+            if let DiffOp::Insert {
+                new_index, new_len, ..
+            } = op
+            {
+                moved.extend(&indices[new_index..new_index + new_len]);
+            }
+        }
+    }
+    moved
+}
+
+impl CodeParsingContext {
+    /// Lists the symbols the edit added, removed, modified or moved. Symbols
+    /// inside an added or removed one are not listed separately.
+    #[tracing::instrument(name = "symbol_changes", skip_all)]
+    pub fn symbol_changes(&mut self, original: &str, edited: &str) -> Vec<SymbolChange> {
+        let symbols = self.extract_symbols_with_range(original);
+        let original = Version {
+            code: original,
+            symbols: self.process_symbols(symbols),
+        };
+        let symbols = self.extract_symbols_with_range(edited);
+        let edited = Version {
+            code: edited,
+            symbols: self.process_symbols(symbols),
+        };
+
+        let original_keys = occurrences(&original.symbols);
+        let edited_keys = occurrences(&edited.symbols);
+        let original_by_key: HashMap<_, _> = original_keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| (*key, index))
+            .collect();
+        let edited_key_set: HashSet<_> = edited_keys.iter().copied().collect();
+        let common: HashSet<_> = edited_keys
+            .iter()
+            .copied()
+            .filter(|key| original_by_key.contains_key(key))
+            .collect();
+        let moved = moved(&original_keys, &edited_keys, &common);
+
+        let mut changes = Vec::new();
+        let mut added = HashSet::new();
+        for (index, key) in edited_keys.iter().enumerate() {
+            let kind = match original_by_key.get(key) {
+                None => {
+                    added.insert(index);
+                    if edited.inside(index, &added) {
+                        continue;
+                    }
+                    ChangeKind::Added
+                }
+                Some(&original_index)
+                    if original.own_text(original_index) != edited.own_text(index) =>
+                {
+                    ChangeKind::Modified
+                }
+                Some(_) if moved.contains(&index) => ChangeKind::Moved,
+                Some(_) => continue,
+            };
+            changes.push(SymbolChange {
+                kind,
+                symbol: key.0.clone(),
+                label: edited.label(index),
+            });
+        }
+        let mut removed = HashSet::new();
+        for (index, key) in original_keys.iter().enumerate() {
+            if edited_key_set.contains(key) {
+                continue;
+            }
+            removed.insert(index);
+            if !original.inside(index, &removed) {
+                changes.push(SymbolChange {
+                    kind: ChangeKind::Removed,
+                    symbol: key.0.clone(),
+                    label: original.label(index),
+                });
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_changes() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let original = "\
+struct FsState;
+
+impl FsState {
+    fn insert(&self) {}
+
+    fn remove(&self) {}
+}
+
+fn a() {}
+
+fn b() {}
+
+mod old {
+    fn inner() {}
+}
+";
+        let edited = "\
+struct FsState;
+
+struct FileMetadata;
+
+impl FsState {
+    fn insert(&self) {
+        todo!()
+    }
+
+    fn remove(&self) {}
+}
+
+fn b() {}
+
+fn a() {}
+";
+        let changes = ctx.symbol_changes(original, edited);
+        assert_eq!(
+            changes.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
+            vec![
+                "added: struct FileMetadata",
+                "modified: impl FsState::insert",
+                "moved: fn b",
+                "removed: mod old",
+            ]
+        );
+        assert_eq!(
+            changes_to_json(&changes[1..2]).to_string(),
+            r#"[{"change":"modified","label":"impl FsState::insert","symbol":"FsState::insert"}]"#
+        );
+    }
+}
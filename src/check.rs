@@ -0,0 +1,89 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Context;
+
+/// Diagnostics beyond this many lines are dropped before they reach the model.
+const MAX_DIAGNOSTIC_LINES: usize = 100;
+
+pub struct CheckOutcome {
+    pub success: bool,
+    /// Output of the command, narrowed to the lines about the edited file.
+    pub diagnostics: String,
+}
+
+/// Runs `command` through the shell, e.g. `cargo check --message-format=short`
+/// or `tsc --noEmit`, after the candidate has been written to `file`.
+#[tracing::instrument(name = "check", skip_all, fields(command, success))]
+pub fn run_check(command: &str, file: &Path) -> anyhow::Result<CheckOutcome> {
+    tracing::Span::current().record("command", command);
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .output()
+        .with_context(|| format!("Failed to run check command: {command}"))?;
+    let success = output.status.success();
+    tracing::Span::current().record("success", success);
+
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    Ok(CheckOutcome {
+        success,
+        diagnostics: filter_diagnostics(&text, file),
+    })
+}
+
+/// Keeps the lines mentioning `file`, by path and otherwise by file name. If
+/// nothing mentions it the error is elsewhere, so the end of the output is kept.
+fn filter_diagnostics(output: &str, file: &Path) -> String {
+    let path = file.to_string_lossy();
+    let path = path.trim_start_matches("./");
+    let file_name = file
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    let lines: Vec<&str> = output.lines().collect();
+    let mut matching: Vec<&str> = lines
+        .iter()
+        .copied()
+        .filter(|line| line.contains(path))
+        .collect();
+    if matching.is_empty() && !file_name.is_empty() {
+        matching = lines
+            .iter()
+            .copied()
+            .filter(|line| line.contains(&*file_name))
+            .collect();
+    }
+    if matching.is_empty() {
+        matching = lines[lines.len().saturating_sub(MAX_DIAGNOSTIC_LINES)..].to_vec();
+    }
+    matching.truncate(MAX_DIAGNOSTIC_LINES);
+    matching.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_diagnostics() {
+        let output = "\
+src/lib.rs:10:5: error[E0425]: cannot find value `x` in this scope
+src/main.rs:3:1: warning: unused import
+error: could not compile `aiply` (lib) due to 1 previous error";
+        assert_eq!(
+            filter_diagnostics(output, Path::new("./src/lib.rs")),
+            "src/lib.rs:10:5: error[E0425]: cannot find value `x` in this scope"
+        );
+        assert_eq!(
+            filter_diagnostics(output, Path::new("/work/src/main.rs")),
+            "src/main.rs:3:1: warning: unused import"
+        );
+        assert_eq!(
+            filter_diagnostics(output, Path::new("src/other.rs")),
+            output
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::ops::Range;
+
+use similar::TextDiff;
+use ureq::{json, serde_json::Value};
+
+use crate::noise::{changed_hunks, line_offsets};
+
+/// A position the way LSP counts it: 0-based line and UTF-16 code unit offset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub character: usize,
+}
+
+/// Replaces a range of the original with `new_text`. All edits refer to the
+/// original, so a client applies them together.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextEdit {
+    pub start: Position,
+    pub end: Position,
+    pub byte_range: Range<usize>,
+    pub new_text: String,
+}
+
+/// Byte offsets of the start of each line, to turn offsets into positions.
+struct LineIndex<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    fn new(text: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        LineIndex { text, line_starts }
+    }
+
+    fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let character = self.text[self.line_starts[line]..offset]
+            .chars()
+            .map(char::len_utf16)
+            .sum();
+        Position { line, character }
+    }
+}
+
+fn common_prefix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, x), y)| x != y)
+        .map_or(a.len().min(b.len()), |((i, _), _)| i)
+}
+
+fn common_suffix(a: &str, b: &str) -> usize {
+    a.chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum()
+}
+
+/// Computes the edits turning `original` into `edited`: one per changed run
+/// of lines, narrowed to the text that actually differs.
+pub fn text_edits(original: &str, edited: &str) -> Vec<TextEdit> {
+    let diff = TextDiff::from_lines(original, edited);
+    let old_offsets = line_offsets(diff.old_slices());
+    let new_offsets = line_offsets(diff.new_slices());
+    let index = LineIndex::new(original);
+
+    let mut edits = Vec::new();
+    for (old, new) in changed_hunks(diff.ops()) {
+        let mut old = old_offsets[old.start]..old_offsets[old.end];
+        let mut new = new_offsets[new.start]..new_offsets[new.end];
+        let prefix = common_prefix(&original[old.clone()], &edited[new.clone()]);
+        old.start += prefix;
+        new.start += prefix;
+        let suffix = common_suffix(&original[old.clone()], &edited[new.clone()]);
+        old.end -= suffix;
+        new.end -= suffix;
+        edits.push(TextEdit {
+            start: index.position(old.start),
+            end: index.position(old.end),
+            byte_range: old,
+            new_text: edited[new].to_owned(),
+        });
+    }
+    edits
+}
+
+fn position_to_json(position: Position) -> Value {
+    json!({ "line": position.line, "character": position.character })
+}
+
+/// The edits as LSP `TextEdit`s, with the byte range added as `byteRange`.
+pub fn edits_to_json(edits: &[TextEdit]) -> Value {
+    edits
+        .iter()
+        .map(|edit| {
+            json!({
+                "range": {
+                    "start": position_to_json(edit.start),
+                    "end": position_to_json(edit.end),
+                },
+                "byteRange": { "start": edit.byte_range.start, "end": edit.byte_range.end },
+                "newText": edit.new_text,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(original: &str, edits: &[TextEdit]) -> String {
+        let mut result = original.to_owned();
+        for edit in edits.iter().rev() {
+            result.replace_range(edit.byte_range.clone(), &edit.new_text);
+        }
+        result
+    }
+
+    #[test]
+    fn test_text_edits() {
+        let original = "fn a() {\n    \"😀\"; 1\n}\n\nfn b() {}\n";
+        let edited = "fn a() {\n    \"😀\"; 2\n}\n\nfn b() {}\nfn c() {}\n";
+        let edits = text_edits(original, edited);
+        assert_eq!(
+            edits,
+            vec![
+                TextEdit {
+                    start: Position {
+                        line: 1,
+                        character: 10
+                    },
+                    end: Position {
+                        line: 1,
+                        character: 11
+                    },
+                    byte_range: 21..22,
+                    new_text: "2".to_owned(),
+                },
+                TextEdit {
+                    start: Position {
+                        line: 5,
+                        character: 0
+                    },
+                    end: Position {
+                        line: 5,
+                        character: 0
+                    },
+                    byte_range: 36..36,
+                    new_text: "fn c() {}\n".to_owned(),
+                },
+            ]
+        );
+        assert_eq!(apply(original, &edits), edited);
+        assert!(text_edits(original, original).is_empty());
+    }
+}
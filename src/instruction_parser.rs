@@ -1,4 +1,5 @@
-use crate::Symbol;
+use crate::markdown_parser::Instruction;
+use crate::{CodeParsingContext, Symbol};
 use regex::Regex;
 use std::sync::OnceLock;
 
@@ -28,6 +29,263 @@ pub fn parse_instruction_symbols(text: &str) -> Vec<Symbol> {
         .collect()
 }
 
+/// Keywords a code span may start with, as in `fn foo` or `impl Foo`.
+const KEYWORDS: &[&str] = &[
+    "fn",
+    "struct",
+    "enum",
+    "trait",
+    "impl",
+    "mod",
+    "type",
+    "const",
+    "static",
+    "let",
+    "pub",
+    "async",
+    "function",
+    "class",
+    "interface",
+    "def",
+    "dyn",
+    "mut",
+    "new",
+];
+
+/// Leading parts naming no item, as in `self.foo` or `Self::new`.
+const RECEIVERS: &[&str] = &["self", "Self", "this", "crate", "super"];
+
+/// The parts of a path like `Foo::bar`, `foo.bar` or `Foo#bar`, if it is one.
+fn path_parts(path: &str) -> Option<Vec<String>> {
+    let is_identifier = |part: &str| {
+        part.chars()
+            .next()
+            .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+            && part
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    };
+    let parts: Vec<&str> = path.split("::").flat_map(|p| p.split(['.', '#'])).collect();
+    if !parts.iter().all(|part| is_identifier(part)) {
+        return None;
+    }
+    let parts: Vec<String> = parts
+        .iter()
+        .skip_while(|part| RECEIVERS.contains(part))
+        .map(|part| part.to_string())
+        .collect();
+    (!parts.is_empty()).then_some(parts)
+}
+
+/// Extensions of the source files instructions name, as in "In `fs.rs`".
+const FILE_EXTENSIONS: &[&str] = &[
+    "rs", "ts", "tsx", "js", "jsx", "mjs", "cjs", "py", "go", "toml", "md",
+];
+
+/// The symbols an inline code span mentions, whatever their shape: `new`,
+/// `fn foo`, `foo.bar()`, `Foo#bar` or `Vec<Foo>`, which mentions both `Vec`
+/// and `Foo`. Spans that are no path, like `cargo test` or `fs.rs`, mention
+/// nothing.
+pub fn parse_code_span_symbols(span: &str) -> Vec<Symbol> {
+    let mut span = span.trim();
+    if span
+        .rsplit_once('.')
+        .is_some_and(|(_, extension)| FILE_EXTENSIONS.contains(&extension))
+    {
+        return Vec::new();
+    }
+    while let Some((keyword, rest)) = span.split_once(' ') {
+        if !KEYWORDS.contains(&keyword) {
+            break;
+        }
+        span = rest.trim_start();
+    }
+    // Call arguments and signatures: `foo.bar(x)`, `fn foo(&self)`.
+    if let Some(paren) = span.find('(') {
+        span = &span[..paren];
+    }
+    let (path, generics) = span.split_once('<').unwrap_or((span, ""));
+    let Some(parts) = path_parts(path.trim_end_matches("::")) else {
+        return Vec::new();
+    };
+    let mut symbols = vec![Symbol { parts }];
+    symbols.extend(
+        generics
+            .split(|c: char| matches!(c, '<' | '>' | ',' | '&' | '[' | ']') || c.is_whitespace())
+            .filter(|word| !word.is_empty() && !word.starts_with('\'') && !KEYWORDS.contains(word))
+            .filter_map(path_parts)
+            .map(|parts| Symbol { parts }),
+    );
+    symbols
+}
+
+/// The symbols mentioned in `text`: multi-part names and anything quoted as
+/// inline code.
+fn mentions(text: &str) -> Vec<Symbol> {
+    static CODE_SPAN: OnceLock<Regex> = OnceLock::new();
+    let code_span = CODE_SPAN.get_or_init(|| Regex::new(r"`([^`]+)`").unwrap());
+    let mut symbols = parse_instruction_symbols(text);
+    for span in code_span.captures_iter(text) {
+        symbols.extend(parse_code_span_symbols(&span[1]));
+    }
+    symbols
+}
+
+/// Splits an instruction into clauses, so that the negation in "update `foo`
+/// but leave `bar` alone" only covers `bar`.
+fn clauses(text: &str) -> Vec<&str> {
+    static SEPARATOR: OnceLock<Regex> = OnceLock::new();
+    static NEGATION_START: OnceLock<Regex> = OnceLock::new();
+    let separator = SEPARATOR.get_or_init(|| {
+        Regex::new(r"(?i)[.;!?\n]\s|[;!?\n]|\b(?:but|while|whereas|and)\b").unwrap()
+    });
+    let negation_start = NEGATION_START
+        .get_or_init(|| Regex::new(r"(?i)^\s*(?:don'?t|do not|never|leave|keep|avoid)\b").unwrap());
+
+    let mut clauses = Vec::new();
+    let mut start = 0;
+    for m in separator.find_iter(text) {
+        // "A and B" is one clause, unless a negation starts after the `and`.
+        if m.as_str().eq_ignore_ascii_case("and") && !negation_start.is_match(&text[m.end()..]) {
+            continue;
+        }
+        clauses.push(&text[start..m.start()]);
+        start = m.end();
+    }
+    clauses.push(&text[start..]);
+    clauses
+}
+
+/// The symbols an instruction says to leave alone, as in "don't touch
+/// `legacy_handler`", "do not modify `Config`" or "keep `Foo` unchanged".
+pub fn excluded_symbols(instruction: &Instruction) -> Vec<Symbol> {
+    static NEGATION: OnceLock<Regex> = OnceLock::new();
+    let negation = NEGATION.get_or_init(|| {
+        Regex::new(
+            r"(?ix)
+            \b(?:don'?t|do\ not|does\ not|doesn'?t|never|must\ not|mustn'?t|should\ not
+                |shouldn'?t|without|avoid)
+            \s+(?:\S+\s+){0,2}?
+            (?:touch|modify|change|edit|alter|update|rewrite|refactor|rename)\w*\b
+           |
+            \b(?:leave|keep)\b.*\b(?:alone|as[\ -]is|untouched|unchanged|intact)\b
+           |
+            \b(?:is|are|stays?|remains?)\s+(?:unchanged|untouched|intact)\b
+        ",
+        )
+        .unwrap()
+    });
+    let mut excluded: Vec<Symbol> = Vec::new();
+    for symbol in clauses(&instruction.text)
+        .into_iter()
+        .filter(|clause| negation.is_match(clause))
+        .flat_map(mentions)
+    {
+        if !excluded.contains(&symbol) {
+            excluded.push(symbol);
+        }
+    }
+    excluded
+}
+
+/// The symbols an instruction mentions, apart from those it says to leave
+/// alone: multi-part names in its text and anything quoted as inline code.
+pub fn instruction_symbols(instruction: &Instruction) -> Vec<Symbol> {
+    let excluded = excluded_symbols(instruction);
+    let mut symbols = parse_instruction_symbols(&instruction.text);
+    for span in &instruction.code_spans {
+        symbols.extend(parse_code_span_symbols(span));
+    }
+    symbols.retain(|symbol| !excluded.contains(symbol));
+    symbols
+}
+
+/// Instruction mentions checked against the symbols of the source.
+#[derive(Debug, Default)]
+pub struct MentionReport {
+    /// The source symbols mentioned, with typos corrected to the symbol meant.
+    pub symbols: Vec<Symbol>,
+    /// Mentions that only matched fuzzily, with the symbol taken for them.
+    pub corrected: Vec<(Symbol, Symbol)>,
+    /// Mentions matching no source symbol, like names of new items or prose
+    /// the regex took for a name.
+    pub unmatched: Vec<Symbol>,
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substituted = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substituted.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// How far apart two names are, ignoring case and `_` so that `fs_state`
+/// and `FsState` are the same, or `None` if too far for a typo: one edit per
+/// four characters.
+fn name_distance(mention: &str, name: &str) -> Option<usize> {
+    let normalize = |name: &str| name.replace('_', "").to_lowercase();
+    let (mention, name) = (normalize(mention), normalize(name));
+    let distance = edit_distance(&mention, &name);
+    (distance <= mention.len().min(name.len()) / 4).then_some(distance)
+}
+
+impl CodeParsingContext {
+    /// Checks instruction mentions against the symbols of `source`. A mention
+    /// matching nothing as is is taken for the closest source symbols whose
+    /// trailing parts all match it fuzzily.
+    #[tracing::instrument(name = "validate_mentions", skip_all, fields(mentions = mentions.len()))]
+    pub fn validate_mentions(&mut self, source: &str, mentions: &[Symbol]) -> MentionReport {
+        let symbols = self.extract_symbols_with_range(source);
+        let source_symbols: Vec<Symbol> = self
+            .process_symbols(symbols)
+            .into_iter()
+            .map(|s| s.symbol)
+            .collect();
+
+        let mut report = MentionReport::default();
+        for mention in mentions {
+            if source_symbols
+                .iter()
+                .any(|symbol| self.symbols_match(symbol, mention))
+            {
+                report.symbols.push(mention.clone());
+                continue;
+            }
+            let scored: Vec<(&Symbol, usize)> = source_symbols
+                .iter()
+                .filter(|symbol| symbol.parts.len() >= mention.parts.len())
+                .filter_map(|symbol| {
+                    let trailing = &symbol.parts[symbol.parts.len() - mention.parts.len()..];
+                    let distances = trailing
+                        .iter()
+                        .zip(&mention.parts)
+                        .map(|(name, part)| name_distance(part, name));
+                    Some((symbol, distances.sum::<Option<usize>>()?))
+                })
+                .collect();
+            let Some(best) = scored.iter().map(|(_, distance)| *distance).min() else {
+                report.unmatched.push(mention.clone());
+                continue;
+            };
+            for (symbol, _) in scored.into_iter().filter(|(_, d)| *d == best) {
+                if !report.symbols.contains(symbol) {
+                    report.symbols.push(symbol.clone());
+                }
+                report.corrected.push((mention.clone(), symbol.clone()));
+            }
+        }
+        report
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,4 +321,100 @@ mod tests {
             assert_eq!(result, expected, "Failed on input: {}", input);
         }
     }
+
+    #[test]
+    fn test_parse_code_span_symbols() {
+        let test_cases = vec![
+            ("new", vec!["#new"]),
+            ("Encode", vec!["#Encode"]),
+            ("Vec<Foo>", vec!["#Vec", "#Foo"]),
+            (
+                "HashMap<&'a str, Vec<u8>>",
+                vec!["#HashMap", "#str", "#Vec", "#u8"],
+            ),
+            ("foo.bar()", vec!["#foo::bar"]),
+            ("Foo#bar", vec!["#Foo::bar"]),
+            ("Foo.bar", vec!["#Foo::bar"]),
+            ("self.files.insert(path, meta)", vec!["#files::insert"]),
+            ("Self::new", vec!["#new"]),
+            ("fn insert(&mut self)", vec!["#insert"]),
+            ("impl FsState", vec!["#FsState"]),
+            ("cargo test", vec![]),
+            ("fs.rs", vec![]),
+            ("src/settings.ts", vec![]),
+            ("--write", vec![]),
+            ("42", vec![]),
+        ];
+
+        for (input, expected) in test_cases {
+            let result = parse_code_span_symbols(input)
+                .into_iter()
+                .map(|s| format!("{s:?}"))
+                .collect::<Vec<_>>();
+            assert_eq!(result, expected, "Failed on input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_mentions() {
+        let mut context = CodeParsingContext::new("rust");
+        let source = "\
+struct FsState;
+
+impl FsState {
+    fn insert_file(&mut self) {}
+}
+";
+        let mentions = parse_instruction_symbols(
+            "Rename FsStat::insertFile, add FsState::total_size and keep HelloWorld",
+        );
+        let report = context.validate_mentions(source, &mentions);
+        let names =
+            |symbols: &[Symbol]| -> Vec<String> { symbols.iter().map(|s| s.to_string()).collect() };
+        assert_eq!(
+            names(&report.symbols),
+            ["FsState::insert_file", "FsState::total_size"]
+        );
+        assert_eq!(report.corrected.len(), 1);
+        assert_eq!(names(&report.unmatched), ["HelloWorld"]);
+        assert_eq!(name_distance("new", "now"), None);
+        assert_eq!(name_distance("fs_state", "FsState"), Some(0));
+    }
+
+    #[test]
+    fn test_excluded_symbols() {
+        let test_cases = vec![
+            ("Leave `legacy_handler` alone.", vec!["#legacy_handler"]),
+            ("Do not modify `Config`.", vec!["#Config"]),
+            (
+                "Update `FsState::insert` but don't touch `FsState::remove`.",
+                vec!["#FsState::remove"],
+            ),
+            (
+                "Change `load` and keep `Settings` and `Env` unchanged",
+                vec!["#Settings", "#Env"],
+            ),
+            ("`Config` stays unchanged; rename `load`", vec!["#Config"]),
+            ("Add `Encode` to `FsState`.", vec![]),
+        ];
+
+        for (input, expected) in test_cases {
+            let instruction = crate::markdown_parser::ParsedLlmOutput::parse(input)
+                .instructions
+                .remove(0);
+            let result = excluded_symbols(&instruction)
+                .into_iter()
+                .map(|s| format!("{s:?}"))
+                .collect::<Vec<_>>();
+            assert_eq!(result, expected, "Failed on input: {}", input);
+        }
+        let instruction = crate::markdown_parser::ParsedLlmOutput::parse(
+            "Update `FsState::insert` but don't touch `FsState::remove`.",
+        )
+        .instructions
+        .remove(0);
+        let symbols = instruction_symbols(&instruction);
+        assert!(symbols.iter().all(|s| s.to_string() == "FsState::insert"));
+        assert!(!symbols.is_empty());
+    }
 }
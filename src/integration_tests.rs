@@ -1,4 +1,4 @@
-use crate::instruction_parser::parse_instruction_symbols;
+use crate::instruction_parser::instruction_symbols;
 use crate::markdown_parser::ParsedLlmOutput;
 use crate::{CodeParsingContext, Symbol};
 use std::fs;
@@ -6,12 +6,12 @@ use std::fs;
 fn run_test(input: &str) -> TestOutput {
     let mut ctx = CodeParsingContext::new("rust");
     let llm_output = ParsedLlmOutput::parse(input);
-    let mut instruction_symbols = Vec::new();
+    let mut mentions = Vec::new();
     let mut code_symbols = Vec::new();
 
     // Extract symbols from instructions
     for instruction in &llm_output.instructions {
-        instruction_symbols.extend(parse_instruction_symbols(&instruction.text));
+        mentions.extend(instruction_symbols(instruction));
     }
 
     // Extract symbols from code changes
@@ -19,14 +19,14 @@ fn run_test(input: &str) -> TestOutput {
         code_symbols.extend(ctx.parse_code_symbols(&code_change.language, &code_change.code));
     }
 
-    instruction_symbols.sort();
-    instruction_symbols.dedup();
+    mentions.sort();
+    mentions.dedup();
     code_symbols.sort();
     code_symbols.dedup();
 
     TestOutput {
         llm_output,
-        instruction_symbols,
+        instruction_symbols: mentions,
         code_symbols,
     }
 }
@@ -58,3 +58,53 @@ fn test_parse_llm_output() {
         });
     }
 }
+
+fn run_collapse_test(language: &str, source: &str, patch: &str) -> String {
+    let mut ctx = CodeParsingContext::new(language);
+    let llm_output = ParsedLlmOutput::parse(patch);
+    let mut important_symbols = Vec::new();
+    for instruction in &llm_output.instructions {
+        important_symbols.extend(instruction_symbols(instruction));
+    }
+    for code_change in &llm_output.code_changes {
+        important_symbols.extend(ctx.parse_code_symbols(&code_change.language, &code_change.code));
+    }
+
+    let collapsed_doc = ctx.collapse_unrelated_symbols(source, important_symbols);
+    let collapsed = collapsed_doc.collapsed_document();
+    assert_eq!(
+        collapsed_doc.uncollapse_document(&collapsed),
+        source,
+        "uncollapsing the unmodified collapsed document must reproduce the source"
+    );
+    collapsed
+}
+
+#[test]
+fn test_collapse_round_trip() {
+    let test_cases = fs::read_dir("src/tests/collapse/inputs")
+        .expect("Failed to read test inputs directory")
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str().map(String::from))
+        .filter(|name| !name.ends_with(".md"));
+
+    for case in test_cases {
+        let (stem, extension) = case.rsplit_once('.').expect("Input without extension");
+        let language = match extension {
+            "rs" => "rust",
+            "ts" => "typescript",
+            _ => panic!("Unsupported test input: {}", case),
+        };
+        let source = fs::read_to_string(format!("src/tests/collapse/inputs/{}", case))
+            .expect("Failed to read test source file");
+        let patch = fs::read_to_string(format!("src/tests/collapse/inputs/{}.md", stem))
+            .expect("Failed to read test patch file");
+
+        insta::with_settings!({
+            snapshot_path => "tests/collapse/snapshots",
+            prepend_module_to_snapshot => false,
+        }, {
+            insta::assert_snapshot!(&*case, run_collapse_test(language, &source, &patch));
+        });
+    }
+}
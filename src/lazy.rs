@@ -0,0 +1,342 @@
+use crate::noise::line_offsets;
+use crate::search_replace::normalize_whitespace;
+use crate::{normalize_language, CodeParsingContext, SymbolWithRange};
+
+/// Whether a line stands for code the patch left out, like
+/// `// ... existing code ...` or `# … rest unchanged`. A comment needs both
+/// an ellipsis and one of the phrases, so that `// TODO: handle errors...`
+/// stays code.
+pub fn is_placeholder(line: &str) -> bool {
+    let trimmed = line.trim();
+    if trimmed == "..." || trimmed == "…" {
+        return true;
+    }
+    let Some(comment) = ["//", "/*", "# ", "<!--"]
+        .iter()
+        .find_map(|prefix| trimmed.strip_prefix(prefix))
+    else {
+        return false;
+    };
+    let comment = comment.to_lowercase();
+    (comment.contains("...") || comment.contains('…'))
+        && ["existing", "unchanged", "rest of", "remaining"]
+            .iter()
+            .any(|word| comment.contains(word))
+}
+
+/// The lines of `text` with their line breaks, and the byte offset each
+/// starts at.
+pub(crate) fn split_lines(text: &str) -> (Vec<&str>, Vec<usize>) {
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let offsets = line_offsets(&lines);
+    (lines, offsets)
+}
+
+fn line_of(offsets: &[usize], byte: usize) -> usize {
+    offsets.partition_point(|&offset| offset <= byte) - 1
+}
+
+/// A symbol of the patch item and where it and its original counterpart start
+/// and end, in lines relative to each item.
+struct Anchor {
+    start: usize,
+    end: usize,
+    original_start: usize,
+    original_end: usize,
+}
+
+impl CodeParsingContext {
+    /// Replaces the placeholders in the items of a code block with the text
+    /// they stand for, taken from the matching item of `source`. Returns
+    /// `None` if nothing was resolved.
+    ///
+    /// The lines around a placeholder anchor it in the original item: by the
+    /// symbols they start or end, or else by their text. The original lines
+    /// between the two anchors replace the placeholder.
+    #[tracing::instrument(name = "resolve_placeholders", skip_all, fields(resolved))]
+    pub fn resolve_placeholders(
+        &mut self,
+        source: &str,
+        language: &str,
+        code: &str,
+    ) -> Option<String> {
+        if !code.lines().any(is_placeholder) {
+            return None;
+        }
+        let language = if language.is_empty() {
+            self.language
+        } else {
+            normalize_language(language)?
+        };
+        if language != self.language {
+            return None;
+        }
+        let symbols = self.extract_symbols_with_range(code);
+        let block_symbols = self.process_symbols(symbols);
+        let symbols = self.extract_symbols_with_range(source);
+        let source_symbols = self.process_symbols(symbols);
+
+        let mut result = String::new();
+        let mut last_end = 0;
+        let mut resolved = 0;
+        for item in block_symbols.iter().filter(|s| s.symbol.parts.len() == 1) {
+            if !code[item.range.clone()].lines().any(is_placeholder) {
+                continue;
+            }
+            let Some(original) = matching_item(code, item, source, &source_symbols) else {
+                continue;
+            };
+            let Some(text) = resolve_item(
+                code,
+                item,
+                &block_symbols,
+                source,
+                original,
+                &source_symbols,
+            ) else {
+                tracing::debug!(symbol = %item.symbol, "Could not anchor placeholders");
+                continue;
+            };
+            result.push_str(&code[last_end..item.range.start]);
+            result.push_str(&text);
+            last_end = item.range.end;
+            resolved += 1;
+        }
+        tracing::Span::current().record("resolved", resolved);
+        result.push_str(&code[last_end..]);
+        (resolved > 0).then_some(result)
+    }
+}
+
+/// The source item a patch item rewrites: one whose path ends with the
+/// item's, preferring the same summary (`impl Foo` over `struct Foo`) and then
+/// the most lines in common.
+fn matching_item<'a>(
+    code: &str,
+    item: &SymbolWithRange,
+    source: &str,
+    source_symbols: &'a [SymbolWithRange],
+) -> Option<&'a SymbolWithRange> {
+    let summary = normalize_whitespace(&code[item.summary_range.clone()]);
+    let lines: Vec<String> = code[item.range.clone()]
+        .lines()
+        .map(normalize_whitespace)
+        .collect();
+    source_symbols
+        .iter()
+        .filter(|s| s.symbol.parts.ends_with(&item.symbol.parts))
+        .max_by_key(|s| {
+            let same_summary = normalize_whitespace(&source[s.summary_range.clone()]) == summary;
+            let common = source[s.range.clone()]
+                .lines()
+                .filter(|line| lines.contains(&normalize_whitespace(line)))
+                .count();
+            (same_summary, common)
+        })
+}
+
+fn resolve_item(
+    code: &str,
+    item: &SymbolWithRange,
+    block_symbols: &[SymbolWithRange],
+    source: &str,
+    original: &SymbolWithRange,
+    source_symbols: &[SymbolWithRange],
+) -> Option<String> {
+    let (lines, offsets) = split_lines(&code[item.range.clone()]);
+    let (original_lines, original_offsets) = split_lines(&source[original.range.clone()]);
+    let prefix = &original.symbol.parts[..original.symbol.parts.len() - item.symbol.parts.len()];
+
+    // Symbols of the item, including itself, that the original item has too.
+    let anchors: Vec<Anchor> = block_symbols
+        .iter()
+        .filter(|s| item.range.start <= s.range.start && s.range.end <= item.range.end)
+        .filter_map(|s| {
+            let path: Vec<String> = prefix.iter().chain(&s.symbol.parts).cloned().collect();
+            let counterpart = if s.range == item.range {
+                original
+            } else {
+                source_symbols.iter().find(|o| {
+                    o.symbol.parts == path
+                        && original.range.start <= o.range.start
+                        && o.range.end <= original.range.end
+                })?
+            };
+            Some(Anchor {
+                start: line_of(&offsets, s.range.start - item.range.start),
+                end: line_of(&offsets, s.range.end - 1 - item.range.start),
+                original_start: line_of(
+                    &original_offsets,
+                    counterpart.range.start - original.range.start,
+                ),
+                original_end: line_of(
+                    &original_offsets,
+                    counterpart.range.end - 1 - original.range.start,
+                ),
+            })
+        })
+        .collect();
+    let find_line = |line: &str, from: usize| {
+        let line = normalize_whitespace(line);
+        (from..original_lines.len()).find(|&i| normalize_whitespace(original_lines[i]) == line)
+    };
+
+    let is_blank = |line: &str| line.trim().is_empty();
+    let mut result = String::new();
+    // First original line not yet accounted for.
+    let mut cursor = 0;
+    for (index, line) in lines.iter().enumerate() {
+        if !is_placeholder(line) {
+            result.push_str(line);
+            continue;
+        }
+        // Blank lines next to the placeholder are kept and anchor nothing.
+        let before = (0..index).rev().find(|&i| !is_blank(lines[i]));
+        let after = (index + 1..lines.len()).find(|&i| !is_blank(lines[i]));
+
+        let mut from = match before {
+            None => 0,
+            Some(before) => {
+                let anchor = anchors
+                    .iter()
+                    .find(|a| a.end == before && a.start != before)
+                    .map(|a| a.original_end)
+                    .or_else(|| {
+                        anchors
+                            .iter()
+                            .find(|a| a.start == before)
+                            .map(|a| a.original_start)
+                    })
+                    .or_else(|| {
+                        // Like below, a changed line is anchored by the last
+                        // kept one before it.
+                        (0..=before)
+                            .rev()
+                            .take_while(|&i| !is_placeholder(lines[i]))
+                            .find_map(|i| Some(find_line(lines[i], cursor)? + (before - i)))
+                    })?;
+                anchor + 1
+            }
+        };
+        let mut to = match after {
+            None => original_lines.len(),
+            Some(after) => anchors
+                .iter()
+                .find(|a| a.start == after)
+                .map(|a| a.original_start)
+                .or_else(|| {
+                    anchors
+                        .iter()
+                        .find(|a| a.end == after)
+                        .map(|a| a.original_end)
+                })
+                .or_else(|| {
+                    // A line the patch changed anchors nothing, so take the
+                    // next one it kept and assume the changed lines replace
+                    // as many original ones.
+                    (after..lines.len())
+                        .take_while(|&i| !is_placeholder(lines[i]))
+                        .find_map(|i| Some(find_line(lines[i], from)?.saturating_sub(i - after)))
+                        .map(|anchor| anchor.max(from))
+                })?,
+        };
+        // The original's blank lines after and before the anchors are
+        // already there in the patch.
+        let blank_before = before.map_or(0, |before| index - before - 1);
+        let mut skipped = 0;
+        while from < to && is_blank(original_lines[from]) && skipped < blank_before {
+            from += 1;
+            skipped += 1;
+        }
+        let blank_after = after.map_or(0, |after| after - index - 1);
+        let mut skipped = 0;
+        while from < to && is_blank(original_lines[to - 1]) && skipped < blank_after {
+            to -= 1;
+            skipped += 1;
+        }
+        if to < from {
+            return None;
+        }
+        result.extend(original_lines[from..to].iter().copied());
+        cursor = to;
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_placeholders() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let source = "\
+struct Foo;
+
+impl Foo {
+    fn a(&self) {
+        1
+    }
+
+    fn b(&self) {
+        2
+    }
+
+    fn c(&self) {
+        3
+    }
+}
+";
+        let code = "\
+impl Foo {
+    // ... existing code ...
+
+    fn b(&self) {
+        20
+    }
+
+    // ... rest unchanged
+}
+";
+        assert_eq!(
+            ctx.resolve_placeholders(source, "rust", code).unwrap(),
+            "\
+impl Foo {
+    fn a(&self) {
+        1
+    }
+
+    fn b(&self) {
+        20
+    }
+
+    fn c(&self) {
+        3
+    }
+}
+"
+        );
+        assert_eq!(
+            ctx.resolve_placeholders(source, "rust", "fn a() {}\n"),
+            None
+        );
+
+        let source = "fn main() {\n    let x = 1;\n    let y = x * 2;\n    println!(\"{y}\");\n}\n";
+        let code =
+            "fn main() {\n    let x = 1;\n    // … unchanged\n    println!(\"{}\", y + 1);\n}\n";
+        assert_eq!(
+            ctx.resolve_placeholders(source, "", code).unwrap(),
+            "fn main() {\n    let x = 1;\n    let y = x * 2;\n    println!(\"{}\", y + 1);\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_is_placeholder() {
+        assert!(is_placeholder("    # ... rest of the file"));
+        assert!(is_placeholder("/* …existing code… */"));
+        assert!(is_placeholder("    ..."));
+        assert!(!is_placeholder("#[derive(Debug)]"));
+        assert!(!is_placeholder("// TODO: handle errors..."));
+        assert!(!is_placeholder("// keep the existing order"));
+    }
+}
@@ -1,21 +1,35 @@
+pub mod changes;
+pub mod check;
+pub mod edits;
 pub mod instruction_parser;
+pub mod lazy;
 pub mod llm;
 pub mod markdown_parser;
-
+pub mod merge;
+pub mod noise;
+pub mod scope;
+pub mod search_replace;
+pub mod unified_diff;
+pub mod validate;
+
+use std::collections::{HashMap, HashSet};
 use std::ops::Range;
 
+use similar::{DiffTag, TextDiff};
 use tree_sitter::{Parser, Query, QueryCursor};
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Symbol {
     pub parts: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct SymbolWithRange {
-    symbol: Symbol,
-    range: Range<usize>,
-    summary_range: Range<usize>,
+pub struct SymbolWithRange {
+    pub symbol: Symbol,
+    /// The whole item, including leading attributes and comments.
+    pub range: Range<usize>,
+    /// The part shown when the item is collapsed, e.g. `pub fn foo`.
+    pub summary_range: Range<usize>,
 }
 
 impl std::fmt::Debug for Symbol {
@@ -24,16 +38,63 @@ impl std::fmt::Debug for Symbol {
     }
 }
 
-pub struct CodeParsingContext {
+impl std::fmt::Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.parts.join("::"))
+    }
+}
+
+/// Parses the `::` form printed by `Display`, e.g. `FsState::insert`.
+impl std::str::FromStr for Symbol {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let parts: Vec<String> = s.trim().split("::").map(|p| p.trim().to_owned()).collect();
+        if parts.iter().any(|part| part.is_empty()) {
+            anyhow::bail!("{s:?} is not a symbol path like `Foo::bar`");
+        }
+        Ok(Symbol { parts })
+    }
+}
+
+/// Languages with a tree-sitter grammar and symbol queries.
+pub const LANGUAGES: &[&str] = &["rust", "typescript", "tsx"];
+
+/// Maps a language name or code block tag (`rs`, `ts`, `rust,ignore`) to one
+/// of [`LANGUAGES`].
+pub fn normalize_language(tag: &str) -> Option<&'static str> {
+    let name = tag
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .next()
+        .unwrap_or_default();
+    match name.to_lowercase().as_str() {
+        "rust" | "rs" => Some("rust"),
+        // The TypeScript grammar parses plain JavaScript too.
+        "typescript" | "ts" | "javascript" | "js" => Some("typescript"),
+        "tsx" | "jsx" => Some("tsx"),
+        _ => None,
+    }
+}
+
+struct Grammar {
     parser: Parser,
     query: Query,
     collapse_query: Query,
+    import_keyword: &'static str,
+}
+
+pub struct CodeParsingContext {
+    /// Language of the source document being edited.
+    language: &'static str,
+    // Grammars for other languages are loaded when a code block needs them.
+    grammars: HashMap<&'static str, Grammar>,
 }
 
 #[derive(Clone)]
 pub enum CollapseReplacement {
     Range(Range<usize>),
-    Imports,
+    /// A run of imports, shown as the language's import keyword.
+    Imports(&'static str),
 }
 
 #[derive(Clone)]
@@ -42,13 +103,77 @@ pub struct Collapse {
     target: Range<usize>,
 }
 
-pub struct CollapsedDocument<'a> {
-    original_document: &'a str,
+pub struct Uncollapsed {
+    pub text: String,
+    /// Lines that look like collapse markers but match no collapsed item,
+    /// as 1-based line number and line text.
+    pub unresolved_markers: Vec<(usize, String)>,
+    /// For each line of `text`, the 1-based reply line it came from and
+    /// whether it was copied as is rather than expanded from a marker.
+    reply_lines: Vec<(usize, bool)>,
+}
+
+impl Uncollapsed {
+    /// Maps a 1-based line and column of `edited`, which is `text` after
+    /// any later fixups, to the position in the model's reply it came from.
+    /// Lines that were expanded from a marker or added by a fixup map to the
+    /// nearest reply line, at column 1.
+    pub fn reply_position(&self, edited: &str, line: usize, column: usize) -> (usize, usize) {
+        let diff = TextDiff::from_lines(self.text.as_str(), edited);
+        let index = line.saturating_sub(1);
+        let mut text_line = (index, false);
+        for op in diff.ops() {
+            let (tag, old, new) = op.as_tag_tuple();
+            if !new.contains(&index) {
+                continue;
+            }
+            text_line = match tag {
+                DiffTag::Equal => (old.start + index - new.start, true),
+                _ => (old.start, false),
+            };
+            break;
+        }
+        let Some(&(reply_line, verbatim)) = self
+            .reply_lines
+            .get(text_line.0)
+            .or(self.reply_lines.last())
+        else {
+            return (1, 1);
+        };
+        if verbatim && text_line.1 {
+            (reply_line, column)
+        } else {
+            (reply_line, 1)
+        }
+    }
+}
+
+/// Whether a reply line has the `<summary> ...` shape of a collapse marker,
+/// ignoring closing punctuation. Comments like `// ...` do not count.
+fn looks_like_marker(line: &str) -> bool {
+    let line = line.trim().trim_end_matches([';', ',']);
+    let Some(summary) = line.strip_suffix(" ...") else {
+        return false;
+    };
+    let summary = summary.trim_end();
+    !summary.is_empty()
+        && !["//", "/*", "*", "#"]
+            .iter()
+            .any(|c| summary.starts_with(c))
+}
+
+pub struct CollapsedDocument {
+    original_document: String,
     // invariant: non overlapping, sorted
     collapses: Vec<Collapse>,
 }
 
-impl<'a> CollapsedDocument<'a> {
+impl CollapsedDocument {
+    /// The document as it was when it was collapsed.
+    pub fn original_document(&self) -> &str {
+        &self.original_document
+    }
+
     pub fn collapsed_document(&self) -> String {
         let mut result = String::new();
         let mut last_end = 0;
@@ -62,8 +187,9 @@ impl<'a> CollapsedDocument<'a> {
                     result.push_str(&self.original_document[range.clone()]);
                     result.push_str(" ...");
                 }
-                CollapseReplacement::Imports => {
-                    result.push_str("use ...");
+                CollapseReplacement::Imports(keyword) => {
+                    result.push_str(keyword);
+                    result.push_str(" ...");
                 }
             }
             last_end = collapse.target.end;
@@ -75,49 +201,86 @@ impl<'a> CollapsedDocument<'a> {
         result
     }
 
+    fn summary(&self, collapse: &Collapse) -> &str {
+        match &collapse.replacement {
+            CollapseReplacement::Range(range) => &self.original_document[range.clone()],
+            CollapseReplacement::Imports(keyword) => keyword,
+        }
+    }
+
+    /// Finds the collapse whose summary ends `text`. The longest summary wins
+    /// so that `pub fn foo ...` is not mistaken for a collapsed `fn foo`.
+    fn find_marker(&self, collapses: &[Collapse], text: &str) -> Option<usize> {
+        let mut best: Option<(usize, usize)> = None;
+        for (index, collapse) in collapses.iter().enumerate() {
+            let summary = self.summary(collapse);
+            let Some(prefix) = text.strip_suffix(summary) else {
+                continue;
+            };
+            let at_boundary = !prefix.ends_with(|c: char| c.is_alphanumeric() || c == '_');
+            if at_boundary && best.is_none_or(|(_, len)| summary.len() > len) {
+                best = Some((index, summary.len()));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+
     pub fn uncollapse_document(&self, new_collapsed: &str) -> String {
+        self.uncollapse(new_collapsed).text
+    }
+
+    #[tracing::instrument(name = "uncollapse", skip_all, fields(unmatched_collapses))]
+    pub fn uncollapse(&self, new_collapsed: &str) -> Uncollapsed {
         let mut result = String::new();
+        let mut unresolved_markers = Vec::new();
+        let mut reply_lines = Vec::new();
         let mut collapses = self.collapses.clone();
-        for line in new_collapsed.lines() {
-            if line.ends_with("...") {
-                let prefix = line.trim_end_matches("...");
-                if let Some((index, collapse)) =
-                    collapses
-                        .iter()
-                        .enumerate()
-                        .find(|(_, c)| match &c.replacement {
-                            CollapseReplacement::Range(range) => {
-                                dbg!(&self.original_document[range.clone()]) == prefix.trim()
-                            }
-                            CollapseReplacement::Imports => prefix.trim() == "use",
-                        })
-                {
-                    let indent = prefix.len() - prefix.trim_start().len();
-                    result.push_str(&prefix[..indent]);
-                    // Use the target range for uncollapsing
-                    result.push_str(&self.original_document[collapse.target.clone()]);
-                    // Remove the matched collapse to avoid duplicate matches
-                    collapses.remove(index);
-                } else {
-                    // If no matching collapse is found, keep the original line
-                    result.push_str(line);
-                }
+        let original_lines: HashSet<&str> = self.original_document.lines().map(str::trim).collect();
+        for (line_index, line) in new_collapsed.lines().enumerate() {
+            // A marker is a collapse summary followed by "...". Text around it
+            // (indentation, `export`, a trailing `;`) is kept as is.
+            let marker = line.match_indices("...").find_map(|(pos, _)| {
+                let before = line[..pos].trim_end();
+                let index = self.find_marker(&collapses, before)?;
+                let prefix = &before[..before.len() - self.summary(&collapses[index]).len()];
+                Some((index, prefix, &line[pos + "...".len()..]))
+            });
+            if let Some((index, prefix, suffix)) = marker {
+                result.push_str(prefix);
+                // Use the target range for uncollapsing
+                let target = &self.original_document[collapses[index].target.clone()];
+                result.push_str(target);
+                result.push_str(suffix);
+                let lines = target.matches('\n').count() + 1;
+                reply_lines.extend(std::iter::repeat_n((line_index + 1, false), lines));
+                // Remove the matched collapse to avoid duplicate matches
+                collapses.remove(index);
             } else {
-                // For lines without "...", keep them as is
+                // If no matching collapse is found, keep the original line
+                if looks_like_marker(line) && !original_lines.contains(line.trim()) {
+                    unresolved_markers.push((line_index + 1, line.to_owned()));
+                }
                 result.push_str(line);
+                reply_lines.push((line_index + 1, true));
             }
             result.push('\n');
         }
-        result
+        tracing::Span::current().record("unmatched_collapses", collapses.len());
+        Uncollapsed {
+            text: result,
+            unresolved_markers,
+            reply_lines,
+        }
     }
 }
 
-impl CodeParsingContext {
-    pub fn new(language: &str) -> Self {
+impl Grammar {
+    fn new(language: &'static str) -> Self {
         let mut parser = Parser::new();
         let ts_language = match language {
             "rust" => tree_sitter_rust::LANGUAGE,
             "typescript" => tree_sitter_typescript::LANGUAGE_TYPESCRIPT,
+            "tsx" => tree_sitter_typescript::LANGUAGE_TSX,
             _ => panic!("Unsupported language"),
         };
         let ts_language = tree_sitter::Language::new(ts_language);
@@ -127,7 +290,7 @@ impl CodeParsingContext {
 
         let query_source = match language {
             "rust" => include_str!("rust_query.scm"),
-            "typescript" => include_str!("ts_query.scm"),
+            "typescript" | "tsx" => include_str!("ts_query.scm"),
             _ => panic!("Unsupported language"),
         };
         let query = Query::new(&ts_language, query_source).expect("Failed to create query");
@@ -135,25 +298,49 @@ impl CodeParsingContext {
             &ts_language,
             match language {
                 "rust" => "(use_declaration)+ @collapse",
-                "typescript" => "(import_statement)+ @collapse",
+                "typescript" | "tsx" => "(import_statement)+ @collapse",
                 _ => panic!("Unsupported language"),
             },
         )
         .expect("Failed to create query");
+        let import_keyword = match language {
+            "rust" => "use",
+            "typescript" | "tsx" => "import",
+            _ => panic!("Unsupported language"),
+        };
 
-        CodeParsingContext {
+        Grammar {
             parser,
             query,
             collapse_query,
+            import_keyword,
         }
     }
 
-    pub fn parse_code_symbols(&mut self, code: &str) -> Vec<Symbol> {
-        let symbols_with_range = self.extract_symbols_with_range(code);
-        self.process_symbols(symbols_with_range)
-            .into_iter()
-            .map(|x| x.symbol)
-            .collect()
+    /// The share of the nodes in the tree of `code` that are errors or
+    /// missing, from 0 for code this grammar parses cleanly.
+    fn error_ratio(&mut self, code: &str) -> f64 {
+        let tree = self.parser.parse(code, None).unwrap();
+        let mut cursor = tree.walk();
+        let (mut nodes, mut errors) = (0usize, 0usize);
+        loop {
+            let node = cursor.node();
+            nodes += 1;
+            if node.is_error() || node.is_missing() {
+                errors += 1;
+            }
+            if cursor.goto_first_child() || cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return errors as f64 / nodes as f64;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
     }
 
     fn extract_symbols_with_range(&mut self, code: &str) -> Vec<SymbolWithRange> {
@@ -173,7 +360,7 @@ impl CodeParsingContext {
                 let capture_name = self.query.capture_names()[capture.index as usize];
                 match capture_name {
                     "name" => {
-                        name = Some((&code[byte_range.clone()]).to_string());
+                        name = Some(code[byte_range.clone()].to_string());
                         summary_start = summary_start.min(byte_range.start);
                         summary_end = summary_end.max(byte_range.end);
                     }
@@ -208,19 +395,118 @@ impl CodeParsingContext {
 
         symbols_with_range
     }
+}
+
+impl CodeParsingContext {
+    pub fn new(language: &str) -> Self {
+        let language = normalize_language(language).expect("Unsupported language");
+        CodeParsingContext {
+            language,
+            grammars: HashMap::from([(language, Grammar::new(language))]),
+        }
+    }
+
+    pub fn language(&self) -> &'static str {
+        self.language
+    }
+
+    fn grammar(&mut self, language: &'static str) -> &mut Grammar {
+        self.grammars
+            .entry(language)
+            .or_insert_with(|| Grammar::new(language))
+    }
+
+    /// Guesses the language of an untagged code block by parsing it with each
+    /// of [`LANGUAGES`]: the fewest error nodes wins, then the most symbols,
+    /// then the source language.
+    #[tracing::instrument(level = "debug", skip_all, ret)]
+    pub fn infer_language(&mut self, code: &str) -> &'static str {
+        let score = |context: &mut Self, language| {
+            let grammar = context.grammar(language);
+            let ratio = grammar.error_ratio(code);
+            (ratio, grammar.extract_symbols_with_range(code).len())
+        };
+        // Starting from the source language, which wins ties.
+        let source_language = self.language;
+        let (ratio, symbols) = score(self, source_language);
+        let mut best = (source_language, ratio, symbols);
+        for &language in LANGUAGES.iter().filter(|&&l| l != source_language) {
+            let (ratio, symbols) = score(self, language);
+            if ratio < best.1 || (ratio == best.1 && symbols > best.2) {
+                best = (language, ratio, symbols);
+            }
+        }
+        best.0
+    }
+
+    /// Extracts the symbols defined in a code block written in `language`.
+    /// The language of untagged blocks is inferred, blocks in languages
+    /// without a grammar yield nothing.
+    pub fn parse_code_symbols(&mut self, language: &str, code: &str) -> Vec<Symbol> {
+        let language = if language.is_empty() {
+            self.infer_language(code)
+        } else {
+            match normalize_language(language) {
+                Some(language) => language,
+                None => return Vec::new(),
+            }
+        };
+        let symbols_with_range = self.grammar(language).extract_symbols_with_range(code);
+        self.process_symbols(symbols_with_range)
+            .into_iter()
+            .map(|x| x.symbol)
+            .collect()
+    }
+
+    pub fn extract_symbols_with_range(&mut self, code: &str) -> Vec<SymbolWithRange> {
+        self.grammar(self.language).extract_symbols_with_range(code)
+    }
+
+    /// The symbols of `source` overlapping the 1-based, inclusive `lines`.
+    pub fn symbols_in_lines(
+        &mut self,
+        source: &str,
+        lines: &std::ops::RangeInclusive<usize>,
+    ) -> Vec<Symbol> {
+        let line_of = |byte: usize| source[..byte].matches('\n').count() + 1;
+        let symbols = self.extract_symbols_with_range(source);
+        self.process_symbols(symbols)
+            .into_iter()
+            .filter(|s| {
+                let start = line_of(s.range.start);
+                let end = line_of(s.range.end.saturating_sub(1).max(s.range.start));
+                start <= *lines.end() && *lines.start() <= end
+            })
+            .map(|s| s.symbol)
+            .collect()
+    }
+
+    pub fn collapse_unrelated_symbols(
+        &mut self,
+        original_doc: &str,
+        important_symbols: Vec<Symbol>,
+    ) -> CollapsedDocument {
+        self.collapse_symbols_excluding(original_doc, important_symbols, &[])
+    }
 
-    pub fn collapse_unrelated_symbols<'a>(
+    /// Like [`Self::collapse_unrelated_symbols`], but also collapses the
+    /// symbols whose path ends with one of `excluded_symbols`, even if they
+    /// are important.
+    #[tracing::instrument(name = "collapse", skip_all, fields(important = important_symbols.len(), excluded = excluded_symbols.len(), collapses))]
+    pub fn collapse_symbols_excluding(
         &mut self,
-        original_doc: &'a str,
+        original_doc: &str,
         important_symbols: Vec<Symbol>,
-    ) -> CollapsedDocument<'a> {
+        excluded_symbols: &[Symbol],
+    ) -> CollapsedDocument {
         let symbols_with_range = self.extract_symbols_with_range(original_doc);
         let processed_symbols = self.process_symbols(symbols_with_range);
         let mut collapses = Vec::new();
-        let tree = self.parser.parse(original_doc, None).unwrap();
+        let grammar = self.grammar(self.language);
+        let tree = grammar.parser.parse(original_doc, None).unwrap();
         let root_node = tree.root_node();
         let mut query_cursor = QueryCursor::new();
-        for m in query_cursor.matches(&self.collapse_query, root_node, original_doc.as_bytes()) {
+        for m in query_cursor.matches(&grammar.collapse_query, root_node, original_doc.as_bytes()) {
             let mut start = usize::MAX;
             let mut end = 0;
             for capture in m.captures {
@@ -230,16 +516,20 @@ impl CodeParsingContext {
             }
             if start < end {
                 collapses.push(Collapse {
-                    replacement: CollapseReplacement::Imports,
+                    replacement: CollapseReplacement::Imports(grammar.import_keyword),
                     target: start..end,
                 });
             }
         }
 
         for symbol in processed_symbols {
-            if !important_symbols
+            let excluded = excluded_symbols
                 .iter()
-                .any(|important| self.symbols_match(&symbol.symbol, important))
+                .any(|excluded| symbol.symbol.parts.ends_with(&excluded.parts));
+            if excluded
+                || !important_symbols
+                    .iter()
+                    .any(|important| self.symbols_match(&symbol.symbol, important))
             {
                 // Collapse the range that's not part of the summary
                 if symbol.range.start < symbol.summary_range.start
@@ -270,8 +560,9 @@ impl CodeParsingContext {
             }
         }
 
+        tracing::Span::current().record("collapses", merged_collapses.len());
         CollapsedDocument {
-            original_document: original_doc,
+            original_document: original_doc.to_owned(),
             collapses: merged_collapses,
         }
     }
@@ -281,7 +572,7 @@ impl CodeParsingContext {
         // Check if symbol is a suffix of important
         if symbol.parts.len() > important.parts.len() {
             let start = symbol.parts.len() - important.parts.len();
-            return &symbol.parts[start..] == &important.parts;
+            return symbol.parts[start..] == important.parts;
         }
 
         // Check if symbol matches the start of important
@@ -293,7 +584,8 @@ impl CodeParsingContext {
         true
     }
 
-    fn process_symbols(
+    /// Qualifies each symbol with the names of the symbols enclosing it.
+    pub fn process_symbols(
         &self,
         mut symbols_with_range: Vec<SymbolWithRange>,
     ) -> Vec<SymbolWithRange> {
@@ -332,6 +624,9 @@ impl CodeParsingContext {
 #[cfg(test)]
 mod integration_tests;
 
+#[cfg(test)]
+mod proptests;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,7 +634,121 @@ mod tests {
     #[test]
     fn test_parse_code_symbols_empty() {
         let mut context = CodeParsingContext::new("rust");
-        let symbols = context.parse_code_symbols("");
+        let symbols = context.parse_code_symbols("rust", "");
         assert_eq!(symbols.len(), 0);
     }
+
+    #[test]
+    fn test_uncollapse_unresolved_markers() {
+        let mut context = CodeParsingContext::new("rust");
+        let source = "fn foo() {\n    1\n}\n\nfn bar() {\n    2\n}\n\nconst HELP: &str = \"\nusage ...\n\";\n";
+        let doc = context.collapse_unrelated_symbols(source, vec![]);
+        assert_eq!(
+            doc.collapsed_document(),
+            "fn foo ...\n\nfn bar ...\n\nconst HELP ...\n"
+        );
+
+        let uncollapsed = doc.uncollapse(
+            "fn bar ...\n\nfn baz() {\n    // ...\n}\nfn qux ...\nconst HELP: &str = \"\nusage ...\n\";\n",
+        );
+        assert_eq!(
+            uncollapsed.text,
+            "fn bar() {\n    2\n}\n\nfn baz() {\n    // ...\n}\nfn qux ...\nconst HELP: &str = \"\nusage ...\n\";\n"
+        );
+        assert_eq!(
+            uncollapsed.unresolved_markers,
+            vec![(6, "fn qux ...".to_owned())]
+        );
+    }
+
+    #[test]
+    fn test_uncollapse_reply_position() {
+        let mut context = CodeParsingContext::new("rust");
+        let source = "fn foo() {\n    1\n}\n\nfn bar() {\n    2\n}\n";
+        let doc = context.collapse_unrelated_symbols(source, vec![]);
+        let uncollapsed = doc.uncollapse("fn foo ...\n\nfn bar() {\n    2 +\n}\n");
+        assert_eq!(
+            uncollapsed.text,
+            "fn foo() {\n    1\n}\n\nfn bar() {\n    2 +\n}\n"
+        );
+        let text = &uncollapsed.text;
+        // Lines after the expanded marker move back up.
+        assert_eq!(uncollapsed.reply_position(text, 6, 7), (4, 7));
+        // Lines inside the expansion point at the marker.
+        assert_eq!(uncollapsed.reply_position(text, 2, 5), (1, 1));
+        // A line a later fixup inserted maps to where it was inserted.
+        let edited = "fn foo() {\n    1\n}\n\n// note\nfn bar() {\n    2 +\n}\n";
+        assert_eq!(uncollapsed.reply_position(edited, 7, 7), (4, 7));
+        assert_eq!(uncollapsed.reply_position(edited, 5, 3), (3, 1));
+    }
+
+    #[test]
+    fn test_infer_language() {
+        let mut context = CodeParsingContext::new("rust");
+        assert_eq!(
+            context.infer_language("export function load(path: string): Settings {}\n"),
+            "typescript"
+        );
+        assert_eq!(
+            context.infer_language("impl Foo {\n    fn bar(&self) {}\n}\n"),
+            "rust"
+        );
+        // Both parse it, so it stays the source language.
+        assert_eq!(context.infer_language("foo(bar);\n"), "rust");
+        assert_eq!(
+            context.parse_code_symbols("", "interface Settings {\n  path: string;\n}\n"),
+            vec![Symbol {
+                parts: vec!["Settings".to_owned()]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_symbols_in_lines() {
+        let mut context = CodeParsingContext::new("rust");
+        let source = "fn a() {}\n\nimpl Foo {\n    fn b() {}\n\n    fn c() {}\n}\n";
+        let names = |symbols: Vec<Symbol>| -> Vec<String> {
+            symbols.iter().map(|s| s.to_string()).collect()
+        };
+        assert_eq!(
+            names(context.symbols_in_lines(source, &(4..=4))),
+            ["Foo", "Foo::b"]
+        );
+        assert_eq!(names(context.symbols_in_lines(source, &(1..=2))), ["a"]);
+    }
+
+    #[test]
+    fn test_symbol_from_str() {
+        assert_eq!(
+            "FsState::insert".parse::<Symbol>().unwrap().parts,
+            ["FsState", "insert"]
+        );
+        assert_eq!(" Config ".parse::<Symbol>().unwrap().parts, ["Config"]);
+        assert!("Foo::".parse::<Symbol>().is_err());
+        assert!("".parse::<Symbol>().is_err());
+    }
+
+    #[test]
+    fn test_tsx() {
+        let mut context = CodeParsingContext::new("tsx");
+        let code = "export function App() {\n  return <div className=\"app\">hi</div>;\n}\n";
+        assert_eq!(
+            context.parse_code_symbols("tsx", code),
+            vec![Symbol {
+                parts: vec!["App".to_owned()]
+            }]
+        );
+        let mut context = CodeParsingContext::new("typescript");
+        assert_eq!(context.infer_language(code), "tsx");
+    }
+
+    #[test]
+    fn test_normalize_language() {
+        assert_eq!(normalize_language("rs"), Some("rust"));
+        assert_eq!(normalize_language("rust,ignore"), Some("rust"));
+        assert_eq!(normalize_language("TypeScript"), Some("typescript"));
+        assert_eq!(normalize_language("tsx"), Some("tsx"));
+        assert_eq!(normalize_language("python"), None);
+        assert_eq!(normalize_language(""), None);
+    }
 }
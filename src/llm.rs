@@ -1,5 +1,3 @@
-use std::time::Instant;
-
 use anyhow::Context;
 use ureq::{json, serde_json::Value};
 
@@ -20,14 +18,16 @@ const INSTRUCTIONS: &str =
 
 Your response should consist solely of the updated code structure.";
 
-pub fn prompt_for_edits(
-    language: &str,
-    collapsed_document: &str,
-    patch: &str,
-) -> anyhow::Result<String> {
-    let start = Instant::now();
-    let prompt = format!(
-        "Given the following file structure:
+/// A chat with the edit model. Follow-up messages keep the earlier turns so
+/// the model can correct its previous answer.
+pub struct EditSession {
+    messages: Vec<Value>,
+}
+
+impl EditSession {
+    pub fn new(language: &str, collapsed_document: &str, patch: &str) -> Self {
+        let prompt = format!(
+            "Given the following file structure:
 
 ```{language}
 {collapsed_document}
@@ -35,32 +35,69 @@ pub fn prompt_for_edits(
 
 Make the follow edits:
 {patch}"
-    );
-    let api_key = std::env::var("SAMBANOVA_API_KEY").expect("SAMBANOVA_API_KEY must be set");
-    // TODO: check if streaming faster
-    let response = ureq::post("https://api.sambanova.ai/v1/chat/completions")
-        .set("Authorization", &format!("Bearer {}", api_key))
-        .send_json(json!({
-            "model": "Meta-Llama-3.1-70B-Instruct",
-            "messages": [
-                { "role": "system", "content": INSTRUCTIONS },
-                { "role": "user", "content": prompt }
+        );
+        EditSession {
+            messages: vec![
+                json!({ "role": "system", "content": INSTRUCTIONS }),
+                json!({ "role": "user", "content": prompt }),
             ],
-            "temperature": 0.0,
-        }))?;
-    let value = response.into_json::<Value>()?;
-    let content = value["choices"][0]["message"]["content"]
-        .as_str()
-        .context("invalid output")?;
-    let trimmed = if content.starts_with("```") {
-        let start = content
-            .find("\n")
-            .map(|x| x + "\n".len())
-            .unwrap_or("```".len());
-        &content[start..]
-    } else {
-        content
-    };
+        }
+    }
+
+    /// Sends the conversation and returns the edited file from the reply.
+    #[tracing::instrument(
+        name = "llm_request",
+        skip_all,
+        fields(turn = self.messages.len() / 2, prompt_tokens, completion_tokens, total_tokens)
+    )]
+    pub fn request(&mut self) -> anyhow::Result<String> {
+        let api_key = std::env::var("SAMBANOVA_API_KEY").expect("SAMBANOVA_API_KEY must be set");
+        // TODO: check if streaming faster
+        let response = ureq::post("https://api.sambanova.ai/v1/chat/completions")
+            .set("Authorization", &format!("Bearer {}", api_key))
+            .send_json(json!({
+                "model": "Meta-Llama-3.1-70B-Instruct",
+                "messages": self.messages,
+                "temperature": 0.0,
+            }))?;
+        let value = response.into_json::<Value>()?;
+        let span = tracing::Span::current();
+        for field in ["prompt_tokens", "completion_tokens", "total_tokens"] {
+            if let Some(tokens) = value["usage"][field].as_u64() {
+                span.record(field, tokens);
+            }
+        }
+        let content = value["choices"][0]["message"]["content"]
+            .as_str()
+            .context("invalid output")?;
+        Ok(self.receive(content))
+    }
+
+    /// Takes a stored reply in place of a [`EditSession::request`], so a
+    /// follow-up continues from it.
+    pub fn replay(&mut self, content: &str) -> String {
+        self.receive(content)
+    }
+
+    fn receive(&mut self, content: &str) -> String {
+        self.messages
+            .push(json!({ "role": "assistant", "content": content }));
+        let trimmed = if content.starts_with("```") {
+            let start = content
+                .find("\n")
+                .map(|x| x + "\n".len())
+                .unwrap_or("```".len());
+            &content[start..]
+        } else {
+            content
+        };
+
+        trimmed.trim_end_matches("\n```").to_owned()
+    }
 
-    Ok(trimmed.trim_end_matches("\n```").to_owned())
+    /// Adds a user message, answered by the next [`EditSession::request`].
+    pub fn follow_up(&mut self, message: &str) {
+        self.messages
+            .push(json!({ "role": "user", "content": message }));
+    }
 }
@@ -1,18 +1,48 @@
-use aiply::instruction_parser::parse_instruction_symbols;
-use aiply::markdown_parser::ParsedLlmOutput;
-use aiply::{llm, CodeParsingContext};
-use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use aiply::changes::changes_to_json;
+use aiply::edits::{edits_to_json, text_edits};
+use aiply::instruction_parser::{excluded_symbols, instruction_symbols};
+use aiply::lazy::is_placeholder;
+use aiply::markdown_parser::{FileOperation, Hunk, ParsedLlmOutput};
+use aiply::merge::merge_into_current;
+use aiply::noise::{suppress_noise, PatchSpans};
+use aiply::scope::revert_changes;
+use aiply::unified_diff::DiffApplication;
+use aiply::validate::ValidationReport;
+use aiply::{check, llm, CodeParsingContext, CollapsedDocument, Symbol, Uncollapsed};
+use anyhow::{bail, Context, Result};
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
 use std::fs;
-use std::path::PathBuf;
+use std::ops::{Range, RangeInclusive};
+use std::path::{Path, PathBuf};
+use tracing::info_span;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
+    /// Increase log verbosity: -v logs phase timings, -vv debug details
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Only log errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Format of the logs written to stderr
+    #[arg(long, value_enum, default_value_t = LogFormat::Text, global = true)]
+    log_format: LogFormat,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+#[derive(Clone, Copy, ValueEnum)]
+enum LogFormat {
+    Text,
+    Json,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Run the full LLM-based code editing process
@@ -31,8 +61,127 @@ struct EditArgs {
     #[arg(short, long)]
     source_file: PathBuf,
 
-    #[arg(short, long)]
+    #[arg(long)]
     language: String,
+
+    /// Write the edited file back to the source file instead of stdout
+    #[arg(short, long)]
+    write: bool,
+
+    /// What to print on stdout
+    #[arg(long, value_enum, default_value_t = Format::File, conflicts_with = "write")]
+    format: Format,
+
+    /// How many times to ask the model for an edit that passes validation
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+    max_attempts: u32,
+
+    /// Shell command checking each candidate after it is written to the source
+    /// file, e.g. "cargo check --message-format=short" or "tsc --noEmit"
+    #[arg(long)]
+    check_cmd: Option<String>,
+
+    /// What to do with changes to symbols the patch never mentioned
+    #[arg(long, value_enum, default_value_t = Scope::Warn)]
+    scope: Scope,
+
+    /// Drop whitespace-only changes and changes outside what the patch touches
+    #[arg(long)]
+    minimal_diff: bool,
+
+    /// Use this stored model reply instead of asking the model on the first attempt
+    #[arg(long)]
+    response: Option<PathBuf>,
+
+    /// Also write the summary of changed symbols as JSON to this file
+    #[arg(long)]
+    changes_json: Option<PathBuf>,
+
+    /// Print the shell commands the patch asks to run instead of editing
+    #[arg(long, conflicts_with_all = ["write", "format"])]
+    print_commands: bool,
+
+    #[command(flatten)]
+    overrides: CollapseOverrides,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// The whole edited file
+    File,
+    /// LSP-style `{range, newText}` edits against the current source file,
+    /// with UTF-16 positions and byte offsets
+    EditsJson,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Scope {
+    /// Revert them to the original
+    Strict,
+    /// Log a warning for each of them
+    Warn,
+    /// Keep them silently
+    Off,
+}
+
+/// Overrides for which symbols of the source stay expanded, as paths like
+/// `FsState::insert`.
+#[derive(Args)]
+struct CollapseOverrides {
+    /// Keep these symbols expanded too
+    #[arg(long, value_name = "SYMBOL", value_delimiter = ',')]
+    keep: Vec<Symbol>,
+
+    /// Collapse these symbols even if the patch mentions them
+    #[arg(long, value_name = "SYMBOL", value_delimiter = ',')]
+    collapse: Vec<Symbol>,
+
+    /// Keep the symbols overlapping these lines expanded, e.g. 120-180
+    #[arg(long, value_name = "START-END", value_parser = parse_line_range, value_delimiter = ',')]
+    keep_lines: Vec<RangeInclusive<usize>>,
+
+    /// Keep only these symbols expanded instead of the ones the patch mentions
+    #[arg(long, value_name = "SYMBOL", value_delimiter = ',')]
+    collapse_all_except: Vec<Symbol>,
+}
+
+impl CollapseOverrides {
+    /// Resolves `--keep-lines` to the symbols on those lines of the source as
+    /// read, so that they mean the same for every patch made from it.
+    fn resolve(&self, context: &mut CodeParsingContext, source_code: &str) -> Overrides {
+        let mut keep = self.keep.clone();
+        for lines in &self.keep_lines {
+            keep.extend(context.symbols_in_lines(source_code, lines));
+        }
+        Overrides {
+            keep,
+            collapse: self.collapse.clone(),
+            collapse_all_except: self.collapse_all_except.clone(),
+        }
+    }
+}
+
+/// [`CollapseOverrides`] with the lines resolved to symbols.
+struct Overrides {
+    keep: Vec<Symbol>,
+    collapse: Vec<Symbol>,
+    collapse_all_except: Vec<Symbol>,
+}
+
+/// Parses 1-based, inclusive lines like `120-180` or `42`.
+fn parse_line_range(text: &str) -> Result<RangeInclusive<usize>> {
+    let (start, end) = text.split_once('-').unwrap_or((text, text));
+    let parse = |line: &str| -> Result<usize> {
+        match line.trim().parse() {
+            Ok(0) | Err(_) => bail!("{line:?} is not a line number"),
+            Ok(line) => Ok(line),
+        }
+    };
+    let (start, end) = (parse(start)?, parse(end)?);
+    if end < start {
+        bail!("{text:?} ends before it starts");
+    }
+    Ok(start..=end)
 }
 
 #[derive(Parser)]
@@ -45,12 +194,16 @@ struct CollapseArgs {
     #[arg(short, long)]
     source_file: PathBuf,
 
-    #[arg(short, long)]
+    #[arg(long)]
     language: String,
+
+    #[command(flatten)]
+    overrides: CollapseOverrides,
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    init_tracing(&cli);
 
     match cli.command {
         Commands::Edit(args) => run_edit(args),
@@ -58,66 +211,1176 @@ fn main() -> Result<()> {
     }
 }
 
-fn run_edit(args: EditArgs) -> Result<()> {
-    let llm_output = fs::read_to_string(&args.llm_output)
-        .with_context(|| format!("Failed to read LLM output file: {:?}", args.llm_output))?;
+fn init_tracing(cli: &Cli) {
+    let level = if cli.quiet {
+        LevelFilter::ERROR
+    } else {
+        match cli.verbose {
+            0 => LevelFilter::WARN,
+            1 => LevelFilter::INFO,
+            2 => LevelFilter::DEBUG,
+            _ => LevelFilter::TRACE,
+        }
+    };
+    // Closing spans log their duration, which gives per-phase timings.
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr);
+    match cli.log_format {
+        LogFormat::Text => subscriber.init(),
+        LogFormat::Json => subscriber.json().init(),
+    }
+}
 
-    let source_code = fs::read_to_string(&args.source_file)
-        .with_context(|| format!("Failed to read source code file: {:?}", args.source_file))?;
+fn run_edit(args: EditArgs) -> Result<()> {
+    let source_code = read_source(&args.source_file)?;
 
-    let parsed_output = ParsedLlmOutput::parse(&llm_output);
     let mut context = CodeParsingContext::new(&args.language);
+    let mut patch = read_patch(
+        &mut context,
+        &source_code,
+        &args.llm_output,
+        &args.source_file,
+    )?;
+    let overrides = args.overrides.resolve(&mut context, &source_code);
+    apply_overrides(&mut patch, &overrides);
+    if args.print_commands {
+        for command in &patch.parsed.commands {
+            print!("{command}");
+        }
+        return Ok(());
+    }
+    if !patch.parsed.commands.is_empty() {
+        tracing::warn!(
+            commands = patch.parsed.commands.len(),
+            "Not running the patch's shell commands, see --print-commands"
+        );
+    }
+    for other in &patch.parsed.other_edits {
+        tracing::warn!(
+            language = other.language,
+            "Skipping code block in a language aiply does not edit"
+        );
+    }
 
-    let mut important_symbols = vec![];
-    for code_changes in parsed_output.code_changes {
-        important_symbols.extend(context.parse_code_symbols(&code_changes.code))
+    let mut current = source_code.clone();
+    let uncollapsed = if !patch.parsed.search_replaces.is_empty() {
+        apply_search_replace(&args, &mut context, &source_code, &mut patch)?
+    } else if !patch.parsed.diffs.is_empty() {
+        let mut applied = apply_diffs(&args, &mut context, &source_code, &mut patch)?;
+        if applied.unplaced.is_empty() {
+            check_edit(&args, &mut context, &source_code, applied.text, &patch)?
+        } else {
+            applied.text = clean_up_edit(&args, &mut context, &source_code, applied.text, &patch);
+            let report = context.validate(&source_code, &applied.text, &patch.important_symbols);
+            if report.is_ok() {
+                tracing::warn!(
+                    unplaced = applied.unplaced.len(),
+                    "Asking the model to apply the hunks that could not be placed"
+                );
+                let mut patch = unplaced_patch(
+                    &mut context,
+                    &args.source_file,
+                    &applied.text,
+                    &patch,
+                    &applied.unplaced,
+                );
+                apply_overrides(&mut patch, &overrides);
+                edit_with_model(&args, &mut context, &applied.text, &patch, &mut current)?
+            } else {
+                // Errors in the model's input would not count against its edit.
+                tracing::warn!(
+                    "Placed hunks break the file, asking the model for the whole patch:\n{report}"
+                );
+                edit_with_model(&args, &mut context, &source_code, &patch, &mut current)?
+            }
+        }
+    } else {
+        edit_with_model(&args, &mut context, &source_code, &patch, &mut current)?
+    };
+
+    match (args.write, args.format) {
+        (true, _) => write_source(&args.source_file, &uncollapsed)?,
+        (false, Format::File) => print!("{uncollapsed}"),
+        (false, Format::EditsJson) => {
+            println!("{}", edits_to_json(&text_edits(&current, &uncollapsed)))
+        }
+    }
+
+    // Against `current`, so edits made to the file meanwhile are not listed.
+    let changes = context.symbol_changes(&current, &uncollapsed);
+    for change in &changes {
+        eprintln!("{change}");
     }
-    for instructions in parsed_output.instructions {
-        important_symbols.extend(parse_instruction_symbols(&instructions.text));
+    if let Some(path) = &args.changes_json {
+        fs::write(path, changes_to_json(&changes).to_string())
+            .with_context(|| format!("Failed to write changes file: {:?}", path))?;
     }
 
-    let start = std::time::Instant::now();
-    let collapsed_doc = context.collapse_unrelated_symbols(&source_code, important_symbols);
-    let duration = start.elapsed();
-    eprintln!("Time taken to collapse unrelated symbols: {:?}", duration);
+    Ok(())
+}
+
+fn read_source(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read source code file: {:?}", path))
+}
+
+fn write_source(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents)
+        .with_context(|| format!("Failed to write source code file: {:?}", path))
+}
+
+/// Has the model apply `patch` to `source_code`, which can differ from the
+/// source file when part of the patch was already applied.
+fn edit_with_model(
+    args: &EditArgs,
+    context: &mut CodeParsingContext,
+    source_code: &str,
+    patch: &Patch,
+    current: &mut String,
+) -> Result<String> {
+    let collapsed_doc = context.collapse_symbols_excluding(
+        source_code,
+        patch.important_symbols.clone(),
+        &patch.folded_symbols(),
+    );
+    let result = request_edit(args, context, &collapsed_doc, patch, current);
+    // The check command needs candidates in the working tree, put the
+    // file back unless the result is meant to be written there.
+    if args.check_cmd.is_some() && (result.is_err() || !args.write) {
+        write_source(&args.source_file, current)?;
+    }
+    result
+}
+
+/// Asks the model for the edit until the result passes validation and the
+/// check command, if any.
+///
+/// `current` tracks the source file apart from the candidates written for the
+/// check command. If it is edited meanwhile, the result is merged into it.
+fn request_edit(
+    args: &EditArgs,
+    context: &mut CodeParsingContext,
+    collapsed_doc: &CollapsedDocument,
+    patch: &Patch,
+    current: &mut String,
+) -> Result<String> {
+    let source_code = collapsed_doc.original_document();
+    let base = current.clone();
+    let important_symbols = &patch.important_symbols;
     let collapsed_text = collapsed_doc.collapsed_document();
-    let start = std::time::Instant::now();
-    let response = llm::prompt_for_edits(&args.language, &collapsed_text, &llm_output)?;
-    let duration = start.elapsed();
-    eprintln!("Time taken to prompt for edits: {:?}", duration);
-    let start = std::time::Instant::now();
-    let uncollapsed = collapsed_doc.uncollapse_document(&response);
-    let duration = start.elapsed();
-    eprintln!("Time taken to uncollapse document: {:?}", duration);
-    println!("{uncollapsed}");
+    let mut session = llm::EditSession::new(&args.language, &collapsed_text, &patch.text);
 
-    Ok(())
+    let mut written: Option<String> = None;
+    let mut attempt = 1;
+    loop {
+        let response = match (&args.response, attempt) {
+            (Some(path), 1) => session.replay(
+                &fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read response file: {:?}", path))?,
+            ),
+            _ => session.request()?,
+        };
+        let uncollapsed = collapsed_doc.uncollapse(&response);
+        let edited = clean_up_edit(args, context, source_code, uncollapsed.text.clone(), patch);
+        let report = context.validate(source_code, &edited, important_symbols);
+        let problems = match attempt_problems(&uncollapsed, &edited, report) {
+            Some(problems) => problems,
+            None => {
+                let on_disk = read_source(&args.source_file)?;
+                if written.as_ref() != Some(&on_disk) {
+                    *current = on_disk;
+                }
+                let merged = merge_into_current(&base, &edited, current);
+                if merged.conflicts > 0 {
+                    tracing::warn!(
+                        conflicts = merged.conflicts,
+                        "Source file changed during the edit, left conflict markers where both changed it"
+                    );
+                }
+                let Some(command) = args.check_cmd.as_ref().filter(|_| merged.conflicts == 0)
+                else {
+                    return Ok(merged.text);
+                };
+                write_source(&args.source_file, &merged.text)?;
+                written = Some(merged.text);
+                let outcome = check::run_check(command, &args.source_file)?;
+                if outcome.success {
+                    return Ok(written.unwrap());
+                }
+                format!("`{command}` failed:\n{}\n", outcome.diagnostics)
+            }
+        };
+        if attempt >= args.max_attempts {
+            bail!("Edit still invalid after {attempt} attempt(s), not writing it:\n{problems}");
+        }
+        tracing::warn!(attempt, "Edited file has problems, retrying:\n{problems}");
+        session.follow_up(&format!(
+            "Your reply has problems:\n{problems}\n\
+             Keep every collapsed item exactly as `<summary> ...` and do not add placeholders. \
+             Reply with the corrected full file."
+        ));
+        attempt += 1;
+    }
 }
 
-fn run_collapse(args: CollapseArgs) -> Result<()> {
-    let llm_output = fs::read_to_string(&args.llm_output)
-        .with_context(|| format!("Failed to read LLM output file: {:?}", args.llm_output))?;
+/// Applies the SEARCH/REPLACE blocks of the patch, which needs no model.
+fn apply_search_replace(
+    args: &EditArgs,
+    context: &mut CodeParsingContext,
+    source_code: &str,
+    patch: &mut Patch,
+) -> Result<String> {
+    if !patch.parsed.code_changes.is_empty() {
+        tracing::warn!(
+            "Patch mixes SEARCH/REPLACE blocks with other code blocks, only applying the former"
+        );
+    }
+    let applied = context.apply_search_replace(
+        source_code,
+        &patch.parsed.search_replaces,
+        &patch.important_symbols,
+    )?;
+    add_placed_symbols(context, source_code, patch, &applied.windows);
+    check_edit(args, context, source_code, applied.text, patch)
+}
 
-    let source_code = fs::read_to_string(&args.source_file)
-        .with_context(|| format!("Failed to read source code file: {:?}", args.source_file))?;
+/// Makes the symbols overlapping the lines of the source an edit made without
+/// the model replaced important, so that the checks do not take its changes
+/// for ones the patch never asked for.
+fn add_placed_symbols(
+    context: &mut CodeParsingContext,
+    source_code: &str,
+    patch: &mut Patch,
+    windows: &[Range<usize>],
+) {
+    for window in windows {
+        let symbols = context.symbols_in_lines(source_code, &(window.start + 1..=window.end));
+        patch
+            .important_symbols
+            .extend(symbols.into_iter().filter(|symbol| {
+                !patch
+                    .excluded_symbols
+                    .iter()
+                    .any(|excluded| symbol.parts.ends_with(&excluded.parts))
+            }));
+    }
+    tracing::debug!(important_symbols = ?patch.important_symbols, "Added the placed symbols");
+}
 
-    let parsed_output = ParsedLlmOutput::parse(&llm_output);
-    let mut context = CodeParsingContext::new(&args.language);
+/// Puts an edit made without the model through the checks the model's edits
+/// get. With no one to retry, an edit that fails them is not written.
+fn check_edit(
+    args: &EditArgs,
+    context: &mut CodeParsingContext,
+    source_code: &str,
+    edited: String,
+    patch: &Patch,
+) -> Result<String> {
+    let edited = clean_up_edit(args, context, source_code, edited, patch);
+    let report = context.validate(source_code, &edited, &patch.important_symbols);
+    if !report.is_ok() {
+        bail!("Edited file has problems, not writing it:\n{report}");
+    }
+    let Some(command) = &args.check_cmd else {
+        return Ok(edited);
+    };
+    write_source(&args.source_file, &edited)?;
+    let outcome = check::run_check(command, &args.source_file)?;
+    if !outcome.success || !args.write {
+        write_source(&args.source_file, source_code)?;
+    }
+    if !outcome.success {
+        bail!(
+            "`{command}` failed, not writing the edit:\n{}",
+            outcome.diagnostics
+        );
+    }
+    Ok(edited)
+}
+
+/// Applies the hunks of the diffs for the source file, which needs no model.
+fn apply_diffs(
+    args: &EditArgs,
+    context: &mut CodeParsingContext,
+    source_code: &str,
+    patch: &mut Patch,
+) -> Result<DiffApplication> {
+    let mut source_code = source_code.to_owned();
+    let mut hunks = Vec::new();
+    for diff in &patch.parsed.diffs {
+        if !is_for_source(&args.source_file, diff.path.as_deref()) {
+            tracing::warn!(path = ?diff.path, "Skipping diff for another file");
+            continue;
+        }
+        match diff.operation {
+            FileOperation::Update => hunks.extend(diff.hunks.iter().cloned()),
+            // The added file replaces whatever is there.
+            FileOperation::Add => {
+                source_code = diff.hunks.iter().map(|hunk| hunk.text(true)).collect();
+                hunks.clear();
+            }
+            FileOperation::Delete => bail!("Patch deletes {:?}, not applying it", args.source_file),
+        }
+        if let Some(move_to) = &diff.move_to {
+            tracing::warn!(move_to, "Not moving the source file as the patch asks");
+        }
+    }
+    let applied = context.apply_hunks(&source_code, &hunks, &patch.important_symbols);
+    add_placed_symbols(context, &source_code, patch, &applied.windows);
+    Ok(applied)
+}
+
+/// A patch asking for the hunks that could not be placed, keeping the
+/// instructions for context.
+fn unplaced_patch(
+    context: &mut CodeParsingContext,
+    source_file: &Path,
+    source_code: &str,
+    patch: &Patch,
+    unplaced: &[Hunk],
+) -> Patch {
+    let mut text = String::new();
+    for instruction in &patch.parsed.instructions {
+        text.push_str(&instruction.text);
+        text.push_str("\n\n");
+    }
+    text.push_str("```diff\n");
+    for hunk in unplaced {
+        text.push_str(&hunk.to_string());
+    }
+    text.push_str("```\n");
+    patch_from_text(context, source_file, source_code, text)
+}
 
+/// Reverts the changes the patch did not ask for: to symbols it says to leave
+/// alone, to symbols outside its scope and, with `--minimal-diff`, noise.
+fn clean_up_edit(
+    args: &EditArgs,
+    context: &mut CodeParsingContext,
+    original: &str,
+    edited: String,
+    patch: &Patch,
+) -> String {
+    let mut edited = protect_excluded(context, original, edited, &patch.excluded_symbols);
+    edited = enforce_scope(
+        args.scope,
+        context,
+        original,
+        edited,
+        &patch.important_symbols,
+    );
+    if args.minimal_diff {
+        edited = minimize_diff(context, original, edited, patch);
+    }
+    edited
+}
+
+/// Reverts the changes to symbols the patch says to leave alone.
+fn protect_excluded(
+    context: &mut CodeParsingContext,
+    original: &str,
+    edited: String,
+    excluded_symbols: &[Symbol],
+) -> String {
+    if excluded_symbols.is_empty() {
+        return edited;
+    }
+    let changes = context.excluded_changes(original, &edited, excluded_symbols);
+    for change in &changes {
+        tracing::warn!(
+            symbol = %change.symbol,
+            "Edit changed a symbol the patch says to leave alone, reverted it"
+        );
+    }
+    revert_changes(original, &edited, &changes)
+}
+
+fn enforce_scope(
+    scope: Scope,
+    context: &mut CodeParsingContext,
+    original: &str,
+    edited: String,
+    important_symbols: &[Symbol],
+) -> String {
+    if scope == Scope::Off {
+        return edited;
+    }
+    let changes = context.out_of_scope_changes(original, &edited, important_symbols);
+    for change in &changes {
+        tracing::warn!(
+            symbol = %change.symbol,
+            reverted = scope == Scope::Strict,
+            "Edit changed a symbol the patch does not mention"
+        );
+    }
+    match scope {
+        Scope::Strict => revert_changes(original, &edited, &changes),
+        Scope::Warn | Scope::Off => edited,
+    }
+}
+
+fn minimize_diff(
+    context: &mut CodeParsingContext,
+    original: &str,
+    edited: String,
+    patch: &Patch,
+) -> String {
+    let code_blocks: Vec<&str> = patch
+        .parsed
+        .code_changes
+        .iter()
+        .map(|c| c.code.as_str())
+        .collect();
+    // Without anything to go on every change would count as noise.
+    let spans = (!code_blocks.is_empty() || !patch.important_symbols.is_empty()).then(|| {
+        PatchSpans::new(
+            context,
+            original,
+            &edited,
+            &code_blocks,
+            &patch.important_symbols,
+        )
+    });
+    suppress_noise(original, &edited, spans.as_ref())
+}
+
+/// Describes what is wrong with an edit attempt, or `None` if it can be written.
+/// Positions point into the model's reply rather than the `edited` file.
+fn attempt_problems(
+    uncollapsed: &Uncollapsed,
+    edited: &str,
+    mut report: ValidationReport,
+) -> Option<String> {
+    for error in &mut report.syntax_errors {
+        (error.line, error.column) = uncollapsed.reply_position(edited, error.line, error.column);
+    }
+    let mut problems = report.to_string();
+    for (line, text) in &uncollapsed.unresolved_markers {
+        problems.push_str(&format!(
+            "line {line} of your reply matches no collapsed item: {}\n",
+            text.trim()
+        ));
+    }
+    (!problems.is_empty()).then_some(problems)
+}
+
+/// The patch being applied and what was derived from it.
+struct Patch {
+    /// The patch as the model is given it, without command, output or
+    /// other-language blocks.
+    text: String,
+    parsed: ParsedLlmOutput,
+    important_symbols: Vec<Symbol>,
+    /// Symbols the instructions say to leave alone.
+    excluded_symbols: Vec<Symbol>,
+    /// Symbols `--collapse` folds whatever the patch says. Unlike the
+    /// excluded ones, the model may still change them.
+    collapsed_symbols: Vec<Symbol>,
+}
+
+impl Patch {
+    /// The symbols to collapse with all they contain.
+    fn folded_symbols(&self) -> Vec<Symbol> {
+        let mut folded = self.excluded_symbols.clone();
+        folded.extend(self.collapsed_symbols.iter().cloned());
+        folded
+    }
+}
+
+/// Whether a block or diff naming `path`, if any, is for the source file.
+fn is_for_source(source_file: &Path, path: Option<&str>) -> bool {
+    path.is_none_or(|path| {
+        let path = Path::new(path);
+        source_file.ends_with(path) || path.ends_with(source_file)
+    })
+}
+
+fn read_patch(
+    context: &mut CodeParsingContext,
+    source_code: &str,
+    path: &Path,
+    source_file: &Path,
+) -> Result<Patch> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read LLM output file: {:?}", path))?;
+    Ok(patch_from_text(context, source_file, source_code, text))
+}
+
+/// Whether an inline code span names a file, like `src/fs.rs` or `fs.rs`
+/// for a source file with the same extension.
+fn names_file(span: &str, source_file: &Path) -> bool {
+    !span.contains(char::is_whitespace)
+        && (span.contains('/')
+            || Path::new(span)
+                .extension()
+                .is_some_and(|extension| Some(extension) == source_file.extension()))
+}
+
+/// Gives the code blocks of a step naming only other files, as in "In
+/// `src/other.rs`, add:", the first of them as their path. Returns the
+/// indices of the instructions of those steps, which describe other files.
+fn assign_step_paths(parsed: &mut ParsedLlmOutput, source_file: &Path) -> Vec<usize> {
+    let mut other_file_instructions = Vec::new();
+    for step in &parsed.steps {
+        let files: Vec<&String> = step
+            .instructions
+            .iter()
+            .flat_map(|&index| &parsed.instructions[index].code_spans)
+            .filter(|span| names_file(span, source_file))
+            .collect();
+        let Some(&file) = files.first() else {
+            continue;
+        };
+        if files
+            .iter()
+            .any(|file| is_for_source(source_file, Some(file.as_str())))
+        {
+            continue;
+        }
+        for &index in &step.code_changes {
+            let change = &mut parsed.code_changes[index];
+            change.path = change.path.take().or_else(|| Some(file.clone()));
+        }
+        other_file_instructions.extend(step.instructions.iter().copied());
+    }
+    other_file_instructions
+}
+
+/// Parses the patch and fills in the placeholders of its code blocks from
+/// `source_code`, in the parsed blocks and the text the model gets alike.
+/// Code blocks tagged with another file's path are dropped.
+fn patch_from_text(
+    context: &mut CodeParsingContext,
+    source_file: &Path,
+    source_code: &str,
+    mut text: String,
+) -> Patch {
+    let _span = info_span!("parse").entered();
+    let mut parsed = ParsedLlmOutput::parse(&text);
+    let other_file_instructions = assign_step_paths(&mut parsed, source_file);
+    parsed.retain_code_changes(|change| {
+        let for_source = is_for_source(source_file, change.path.as_deref());
+        if !for_source {
+            tracing::warn!(path = ?change.path, "Skipping code block for another file");
+        }
+        for_source
+    });
+    // Resolved code for its block, or nothing for a block not to prompt with.
+    let mut replacements: Vec<(Range<usize>, Option<String>)> = parsed
+        .non_edit_blocks
+        .iter()
+        .map(|range| (range.clone(), None))
+        .collect();
     let mut important_symbols = vec![];
-    for code_changes in parsed_output.code_changes {
-        important_symbols.extend(context.parse_code_symbols(&code_changes.code))
+    for code_change in &mut parsed.code_changes {
+        if code_change.language.is_empty() {
+            code_change.language = context.infer_language(&code_change.code).to_owned();
+        }
+        // A block naming its symbol needs no guessing from the code.
+        match &code_change.target_symbol {
+            Some(symbol) => important_symbols.push(Symbol {
+                parts: symbol
+                    .split("::")
+                    .flat_map(|part| part.split('.'))
+                    .map(str::to_owned)
+                    .collect(),
+            }),
+            // As written: the items a placeholder stands for are not edited.
+            None => {
+                let written: String = code_change
+                    .code
+                    .split_inclusive('\n')
+                    .filter(|line| !is_placeholder(line))
+                    .collect();
+                important_symbols
+                    .extend(context.parse_code_symbols(&code_change.language, &written));
+            }
+        }
+        if let Some(resolved) =
+            context.resolve_placeholders(source_code, &code_change.language, &code_change.code)
+        {
+            if let Some(range) = code_change.source_range.clone() {
+                replacements.push((range, Some(resolved.clone())));
+            }
+            code_change.code = resolved;
+        }
+    }
+    replacements.sort_by_key(|(range, _)| range.start);
+    // From the end, so that the earlier ranges stay valid.
+    for (mut range, resolved) in replacements.into_iter().rev() {
+        // A block in a list item or a quote has a prefix on each line.
+        let line_start = text[..range.start].rfind('\n').map_or(0, |i| i + 1);
+        let prefix = text[line_start..range.start].to_owned();
+        let Some(resolved) = resolved else {
+            if prefix.trim_start_matches([' ', '\t', '>']).is_empty() {
+                range.start = line_start;
+                range.end += usize::from(text[range.end..].starts_with('\n'));
+            }
+            text.replace_range(range, "");
+            continue;
+        };
+        let mut lines = resolved.split_inclusive('\n');
+        let mut block: String = lines.next().unwrap_or_default().to_owned();
+        for line in lines {
+            if line.trim().is_empty() {
+                block.push_str(prefix.trim_end());
+            } else {
+                block.push_str(&prefix);
+            }
+            block.push_str(line);
+        }
+        text.replace_range(range, &block);
+    }
+
+    // Hunks and SEARCH/REPLACE blocks are for the source file, their language
+    // needs no guessing.
+    let language = context.language();
+    for block in &parsed.search_replaces {
+        important_symbols.extend(context.parse_code_symbols(language, &block.search));
+        important_symbols.extend(context.parse_code_symbols(language, &block.replace));
+    }
+    for hunk in parsed.diffs.iter().flat_map(|diff| &diff.hunks) {
+        important_symbols.extend(header_symbols(context, language, hunk));
+        important_symbols.extend(context.parse_code_symbols(language, &hunk.text(false)));
+        important_symbols.extend(context.parse_code_symbols(language, &hunk.text(true)));
+    }
+    let mut mentions: Vec<Symbol> = parsed
+        .instructions
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !other_file_instructions.contains(index))
+        .flat_map(|(_, instruction)| instruction_symbols(instruction))
+        .collect();
+    mentions.sort();
+    mentions.dedup();
+    let mentions = context.validate_mentions(source_code, &mentions);
+    for (mention, symbol) in &mentions.corrected {
+        tracing::info!(%mention, %symbol, "Taking a mention for a similar source symbol");
+    }
+    for mention in &mentions.unmatched {
+        tracing::info!(%mention, "Instructions mention a symbol not in the source");
+    }
+    important_symbols.extend(mentions.symbols);
+    // They may name items the patch adds, which --minimal-diff has to keep.
+    important_symbols.extend(mentions.unmatched);
+
+    let excluded: Vec<Symbol> = parsed
+        .instructions
+        .iter()
+        .flat_map(excluded_symbols)
+        .collect();
+    let excluded_symbols = context.validate_mentions(source_code, &excluded).symbols;
+    important_symbols.retain(|symbol| {
+        !excluded_symbols
+            .iter()
+            .any(|excluded| symbol.parts.ends_with(&excluded.parts))
+    });
+    tracing::debug!(?important_symbols, ?excluded_symbols);
+    Patch {
+        text,
+        parsed,
+        important_symbols,
+        excluded_symbols,
+        collapsed_symbols: Vec::new(),
     }
-    for instructions in parsed_output.instructions {
-        important_symbols.extend(parse_instruction_symbols(&instructions.text));
+}
+
+/// The symbols the headers of a hunk name. Stacked V4A headers like
+/// `impl FsState` and `pub fn remove(...)` name one symbol together,
+/// `FsState::remove`.
+fn header_symbols(context: &mut CodeParsingContext, language: &str, hunk: &Hunk) -> Vec<Symbol> {
+    let mut symbols: Vec<Vec<Symbol>> = hunk
+        .headers
+        .iter()
+        .map(|header| {
+            let symbols = context.parse_code_symbols(language, header);
+            if !symbols.is_empty() {
+                return symbols;
+            }
+            // A header is the first line of an item, which parses once closed.
+            let body = if header.ends_with('{') { "}" } else { " {}" };
+            context.parse_code_symbols(language, &format!("{header}{body}"))
+        })
+        .collect();
+    if symbols.len() == 1 {
+        return symbols.pop().unwrap();
+    }
+    let parts: Vec<String> = symbols
+        .into_iter()
+        .filter_map(|symbols| symbols.into_iter().next())
+        .flat_map(|symbol| symbol.parts)
+        .collect();
+    if parts.is_empty() {
+        Vec::new()
+    } else {
+        vec![Symbol { parts }]
+    }
+}
+
+/// Applies the command line overrides to the symbols the patch made important
+/// and excluded. Kept symbols win over the instructions, collapsed ones over
+/// everything.
+fn apply_overrides(patch: &mut Patch, overrides: &Overrides) {
+    if !overrides.collapse_all_except.is_empty() {
+        patch.important_symbols = overrides.collapse_all_except.clone();
     }
+    patch.excluded_symbols.retain(|excluded| {
+        !overrides
+            .keep
+            .iter()
+            .any(|kept| kept.parts.ends_with(&excluded.parts))
+    });
+    patch
+        .important_symbols
+        .extend(overrides.keep.iter().cloned());
+
+    // Symbols inside a collapsed one are hidden with it.
+    patch.important_symbols.retain(|symbol| {
+        !overrides.collapse.iter().any(|collapse| {
+            (1..=symbol.parts.len()).any(|len| symbol.parts[..len].ends_with(&collapse.parts))
+        })
+    });
+    patch
+        .collapsed_symbols
+        .extend(overrides.collapse.iter().cloned());
+    tracing::debug!(important_symbols = ?patch.important_symbols, collapsed_symbols = ?patch.collapsed_symbols, "Applied overrides");
+}
+
+fn run_collapse(args: CollapseArgs) -> Result<()> {
+    let source_code = read_source(&args.source_file)?;
+
+    let mut context = CodeParsingContext::new(&args.language);
+    let mut patch = read_patch(
+        &mut context,
+        &source_code,
+        &args.llm_output,
+        &args.source_file,
+    )?;
+    let overrides = args.overrides.resolve(&mut context, &source_code);
+    apply_overrides(&mut patch, &overrides);
 
-    let start = std::time::Instant::now();
-    let collapsed_doc = context.collapse_unrelated_symbols(&source_code, important_symbols);
-    let duration = start.elapsed();
-    eprintln!("Time taken to collapse unrelated symbols: {:?}", duration);
+    let collapsed_doc = context.collapse_symbols_excluding(
+        &source_code,
+        patch.important_symbols.clone(),
+        &patch.folded_symbols(),
+    );
     let collapsed_text = collapsed_doc.collapsed_document();
     println!("{collapsed_text}");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_range() {
+        assert_eq!(parse_line_range("120-180").unwrap(), 120..=180);
+        assert_eq!(parse_line_range("42").unwrap(), 42..=42);
+        assert_eq!(parse_line_range(" 7 - 9 ").unwrap(), 7..=9);
+        assert!(parse_line_range("0-3").is_err());
+        assert!(parse_line_range("5-3").is_err());
+        assert!(parse_line_range("a-b").is_err());
+        assert!(parse_line_range("").is_err());
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let source = "\
+struct Config {
+    path: String,
+}
+
+impl FsState {
+    fn insert() {}
+
+    fn remove() {}
+}
+
+fn legacy() {}
+";
+        let text = "Update `FsState::insert` and `Config::path`, but don't touch `legacy`.\n";
+        let names =
+            |symbols: &[Symbol]| -> Vec<String> { symbols.iter().map(|s| s.to_string()).collect() };
+        let cli = Cli::try_parse_from([
+            "aiply",
+            "collapse",
+            "--llm-output=patch.md",
+            "--source-file=fs.rs",
+            "--language=rust",
+            "--keep-lines=11",
+            "--collapse=Config,Unknown",
+        ])
+        .unwrap();
+        let Commands::Collapse(args) = cli.command else {
+            unreachable!()
+        };
+        let overrides = args.overrides.resolve(&mut ctx, source);
+        let mut patch = patch_from_text(&mut ctx, Path::new("fs.rs"), source, text.to_owned());
+        apply_overrides(&mut patch, &overrides);
+        assert_eq!(
+            names(&patch.important_symbols),
+            ["FsState::insert", "legacy"]
+        );
+        // `--collapse` folds `Config`, but does not protect it like `legacy` was.
+        assert!(patch.excluded_symbols.is_empty());
+        assert_eq!(names(&patch.collapsed_symbols), ["Config", "Unknown"]);
+        let collapsed = ctx.collapse_symbols_excluding(
+            source,
+            patch.important_symbols.clone(),
+            &patch.folded_symbols(),
+        );
+        assert!(collapsed
+            .collapsed_document()
+            .starts_with("struct Config ...\n"));
+
+        let cli = Cli::try_parse_from([
+            "aiply",
+            "collapse",
+            "--llm-output=patch.md",
+            "--source-file=fs.rs",
+            "--language=rust",
+            "--collapse-all-except=FsState::remove,Config",
+            "--keep=legacy",
+        ])
+        .unwrap();
+        let Commands::Collapse(args) = cli.command else {
+            unreachable!()
+        };
+        let overrides = args.overrides.resolve(&mut ctx, source);
+        let mut patch = patch_from_text(&mut ctx, Path::new("fs.rs"), source, text.to_owned());
+        apply_overrides(&mut patch, &overrides);
+        assert_eq!(
+            names(&patch.important_symbols),
+            ["FsState::remove", "Config", "legacy"]
+        );
+        assert!(patch.excluded_symbols.is_empty());
+    }
+
+    #[test]
+    fn test_patch_from_text_steps() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let source = "struct FsState;\n\nfn helper() {}\n";
+        let text = "\
+1. In `src/other.rs`, call `helper`:
+
+   ```rust
+   fn other() { helper() }
+   ```
+2. In `fs.rs`, derive `Debug` on `FsState`:
+
+   ```rust
+   #[derive(Debug)]
+   struct FsState;
+   ```
+";
+        let patch = patch_from_text(&mut ctx, Path::new("src/fs.rs"), source, text.to_owned());
+        assert_eq!(patch.parsed.code_changes.len(), 1);
+        assert_eq!(patch.parsed.steps[1].code_changes, vec![0]);
+        assert!(patch.parsed.steps[0].code_changes.is_empty());
+        // `helper` is only mentioned by the step for the other file.
+        let mut important: Vec<String> = patch
+            .important_symbols
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        important.dedup();
+        assert_eq!(important, vec!["FsState", "Debug"]);
+    }
+
+    #[test]
+    fn test_patch_from_text_unmatched_mentions() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let source = "struct FsState;\n";
+        let text = "Add a `total_size` function next to `FsStat`.\n";
+        let patch = patch_from_text(&mut ctx, Path::new("fs.rs"), source, text.to_owned());
+        assert_eq!(
+            format!("{:?}", patch.important_symbols),
+            "[#FsState, #total_size]"
+        );
+
+        // The new item counts as part of the patch, not as noise.
+        let edited = "struct FsState;\n\nfn total_size() -> u64 {\n    0\n}\n";
+        let spans = PatchSpans::new(&mut ctx, source, edited, &[], &patch.important_symbols);
+        assert_eq!(suppress_noise(source, edited, Some(&spans)), edited);
+    }
+
+    #[test]
+    fn test_patch_from_text_prompt() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let text = "\
+Add bincode:
+
+```bash
+cargo add bincode
+```
+
+1. Derive it:
+
+   ```rust
+   #[derive(Encode)]
+   struct FsState;
+   ```
+
+   > ```text
+   > Compiling fs
+   > ```
+2. Bump it:
+
+   ```toml
+   bincode = \"2\"
+   ```
+";
+        let patch = patch_from_text(
+            &mut ctx,
+            Path::new("src/fs.rs"),
+            "struct FsState;\n",
+            text.to_owned(),
+        );
+        assert_eq!(patch.parsed.commands, vec!["cargo add bincode\n"]);
+        assert_eq!(patch.parsed.other_edits.len(), 1);
+        assert_eq!(
+            patch.text,
+            "\
+Add bincode:
+
+
+1. Derive it:
+
+   ```rust
+   #[derive(Encode)]
+   struct FsState;
+   ```
+
+2. Bump it:
+
+"
+        );
+    }
+
+    #[test]
+    fn test_patch_from_text_placeholders() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let source =
+            "impl Foo {\n    fn a() {\n        1\n    }\n\n    fn b() {\n        2\n    }\n}\n";
+        let resolved =
+            "impl Foo {\n    fn a() {\n        1\n    }\n\n    fn b() {\n        20\n    }\n}\n";
+        let text = "\
+1. Change `b`:
+
+   ```rust
+   impl Foo {
+       // ... existing code ...
+
+       fn b() {
+           20
+       }
+   }
+   ```
+
+> ```rust
+> impl Foo {
+>     // ... existing code ...
+>
+>     fn b() {
+>         20
+>     }
+> }
+> ```
+";
+        let patch = patch_from_text(&mut ctx, Path::new("foo.rs"), source, text.to_owned());
+        let codes: Vec<&str> = patch
+            .parsed
+            .code_changes
+            .iter()
+            .map(|c| c.code.as_str())
+            .collect();
+        assert_eq!(codes, vec![resolved, resolved]);
+        // `a` only stands behind a placeholder, the blocks do not edit it.
+        let important: Vec<String> = patch
+            .important_symbols
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(important, ["Foo", "Foo::b", "Foo", "Foo::b", "b"]);
+        let prefixed = |prefix: &str| {
+            resolved
+                .lines()
+                .map(|line| format!("{prefix}{line}").trim_end().to_owned() + "\n")
+                .collect::<String>()
+        };
+        assert_eq!(
+            patch.text,
+            format!(
+                "1. Change `b`:\n\n   ```rust\n{}   ```\n\n> ```rust\n{}> ```\n",
+                prefixed("   "),
+                prefixed("> ")
+            )
+        );
+    }
+
+    fn edit_args(args: &[&str]) -> EditArgs {
+        let cli = Cli::try_parse_from(
+            [
+                "aiply",
+                "edit",
+                "--llm-output=patch.md",
+                "--source-file=fs.rs",
+                "--language=rust",
+            ]
+            .iter()
+            .chain(args),
+        )
+        .unwrap();
+        let Commands::Edit(args) = cli.command else {
+            unreachable!()
+        };
+        args
+    }
+
+    const HASH_SOURCE: &str = "\
+struct ContentHash([u8; 32]);
+
+fn hash_bytes(bytes: &[u8]) -> ContentHash {
+    let mut out = [0; 32];
+    for (i, b) in bytes.iter().enumerate() {
+        out[i % 32] ^= b;
+    }
+    ContentHash(out)
+}
+
+fn main() {}
+";
+
+    #[test]
+    fn test_apply_search_replace_strict_scope() {
+        let mut ctx = CodeParsingContext::new("rust");
+        // The block names no symbol, only where it matched tells it edits
+        // `hash_bytes`.
+        let text = "\
+Mix the bytes in with a wrapping add.
+
+```rust
+<<<<<<< SEARCH
+        out[i % 32] ^= b;
+=======
+        out[i % 32] = out[i % 32].wrapping_add(*b);
+>>>>>>> REPLACE
+```
+";
+        let args = edit_args(&["--scope=strict"]);
+        let mut patch = patch_from_text(&mut ctx, Path::new("fs.rs"), HASH_SOURCE, text.to_owned());
+        let edited = apply_search_replace(&args, &mut ctx, HASH_SOURCE, &mut patch).unwrap();
+        assert_eq!(
+            edited,
+            HASH_SOURCE.replace("^= b;", "= out[i % 32].wrapping_add(*b);")
+        );
+    }
+
+    #[test]
+    fn test_apply_search_replace_removes_item() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let text = "\
+Remove it, nothing calls it.
+
+```rust
+<<<<<<< SEARCH
+fn hash_bytes(bytes: &[u8]) -> ContentHash {
+    let mut out = [0; 32];
+    for (i, b) in bytes.iter().enumerate() {
+        out[i % 32] ^= b;
+    }
+    ContentHash(out)
+}
+
+=======
+>>>>>>> REPLACE
+```
+";
+        let args = edit_args(&["--scope=strict"]);
+        let mut patch = patch_from_text(&mut ctx, Path::new("fs.rs"), HASH_SOURCE, text.to_owned());
+        let edited = apply_search_replace(&args, &mut ctx, HASH_SOURCE, &mut patch).unwrap();
+        assert_eq!(edited, "struct ContentHash([u8; 32]);\n\nfn main() {}\n");
+    }
+
+    #[test]
+    fn test_apply_diffs_strict_scope() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let args = edit_args(&["--scope=strict"]);
+        let mut apply = |text: &str| {
+            let mut patch =
+                patch_from_text(&mut ctx, Path::new("fs.rs"), HASH_SOURCE, text.to_owned());
+            let applied = apply_diffs(&args, &mut ctx, HASH_SOURCE, &mut patch).unwrap();
+            assert!(applied.unplaced.is_empty());
+            check_edit(&args, &mut ctx, HASH_SOURCE, applied.text, &patch).unwrap()
+        };
+        // A hunk without a header, inside `hash_bytes`.
+        let edited = apply(
+            "\
+```diff
+@@ -5,3 +5,3 @@
+     for (i, b) in bytes.iter().enumerate() {
+-        out[i % 32] ^= b;
++        out[i % 32] = out[i % 32].wrapping_add(*b);
+     }
+```
+",
+        );
+        assert_eq!(
+            edited,
+            HASH_SOURCE.replace("^= b;", "= out[i % 32].wrapping_add(*b);")
+        );
+        let edited = apply(
+            "\
+```diff
+@@ -3,8 +3,0 @@
+-fn hash_bytes(bytes: &[u8]) -> ContentHash {
+-    let mut out = [0; 32];
+-    for (i, b) in bytes.iter().enumerate() {
+-        out[i % 32] ^= b;
+-    }
+-    ContentHash(out)
+-}
+-
+```
+",
+        );
+        assert_eq!(edited, "struct ContentHash([u8; 32]);\n\nfn main() {}\n");
+    }
+
+    #[test]
+    fn test_header_symbols() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let text = "\
+*** Begin Patch
+*** Update File: fs.rs
+@@ impl FsState
+@@     pub fn remove(&mut self, path: &FilePath) -> Option<FileMetadata> {
+-        self.files.remove(path)
++        self.files.remove(path).inspect(|_| tracing::debug!(?path, \"removed\"))
+*** End Patch
+
+```diff
+@@ -1,3 +1,3 @@ impl FsState {
+-    x
++    y
+```
+";
+        let parsed = ParsedLlmOutput::parse(text);
+        let names: Vec<Vec<String>> = parsed
+            .diffs
+            .iter()
+            .flat_map(|diff| &diff.hunks)
+            .map(|hunk| {
+                header_symbols(&mut ctx, "rust", hunk)
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(names, [vec!["FsState::remove"], vec!["FsState"]]);
+    }
+}
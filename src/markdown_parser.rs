@@ -1,52 +1,717 @@
+use std::fmt;
+use std::ops::Range;
+use std::sync::OnceLock;
+
 use pulldown_cmark::{CodeBlockKind, Event, Parser as MarkdownParser, Tag, TagEnd};
+use regex::Regex;
 
-#[derive(Clone, Debug)]
+use crate::normalize_language;
+
+#[derive(Clone, Debug, Default)]
 pub struct Instruction {
     pub text: String,
+    /// The inline code spans of the text, like `FsState::insert`.
+    pub code_spans: Vec<String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct CodeChange {
     pub language: String,
     pub code: String,
+    /// The file a `<file path="...">` block is for.
+    pub path: Option<String>,
+    /// The symbol an `<edit symbol="FsState::insert">` block replaces.
+    pub target_symbol: Option<String>,
+    /// Where the code is in the parsed text. In a list item or a quote, the
+    /// lines after the first start with the container's prefix there.
+    pub source_range: Option<Range<usize>>,
+}
+
+/// An aider-style `<<<<<<< SEARCH` / `=======` / `>>>>>>> REPLACE` block,
+/// which says exactly what to replace.
+#[derive(Clone, Debug)]
+pub struct SearchReplace {
+    pub language: String,
+    pub search: String,
+    pub replace: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HunkLine {
+    Context(String),
+    Removed(String),
+    Added(String),
+}
+
+/// A hunk of a unified diff. Models often get the line numbers wrong or leave
+/// them out, so the hunk is placed by its context.
+#[derive(Clone, Debug)]
+pub struct Hunk {
+    /// 1-based line the hunk claims to start at in the original.
+    pub old_start: Option<usize>,
+    /// Text after the `@@ ... @@`, usually the enclosing item like `fn foo`.
+    /// A V4A hunk can have several, each inside the one before, like
+    /// `impl FsState` and `pub fn remove(...)`.
+    pub headers: Vec<String>,
+    pub lines: Vec<HunkLine>,
+}
+
+impl Hunk {
+    /// The lines the hunk expects, or with `new`, the lines it leaves.
+    pub fn text(&self, new: bool) -> String {
+        let mut text = String::new();
+        for line in &self.lines {
+            match line {
+                HunkLine::Context(line) => text.push_str(line),
+                HunkLine::Removed(line) if !new => text.push_str(line),
+                HunkLine::Added(line) if new => text.push_str(line),
+                _ => continue,
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl fmt::Display for Hunk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.old_start {
+            Some(start) => write!(f, "@@ -{start} @@")?,
+            None => write!(f, "@@ @@")?,
+        }
+        if !self.headers.is_empty() {
+            write!(f, " {}", self.headers.join(" "))?;
+        }
+        writeln!(f)?;
+        for line in &self.lines {
+            match line {
+                HunkLine::Context(line) => writeln!(f, " {line}")?,
+                HunkLine::Removed(line) => writeln!(f, "-{line}")?,
+                HunkLine::Added(line) => writeln!(f, "+{line}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileOperation {
+    /// Creates the file, its content being the added lines.
+    Add,
+    Delete,
+    Update,
+}
+
+/// The changes a diff makes to one file, from a unified diff or an
+/// `*** Begin Patch` (V4A) patch.
+#[derive(Clone, Debug)]
+pub struct FileDiff {
+    pub operation: FileOperation,
+    /// Path of the file, without the `a/` or `b/` prefix.
+    pub path: Option<String>,
+    /// New path of a file the patch renames.
+    pub move_to: Option<String>,
+    pub hunks: Vec<Hunk>,
+}
+
+/// What a code block is for, judged by its fence tag and content.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockKind {
+    /// Code in a language aiply edits, or without a language.
+    Edit,
+    /// Code for a file in another language, like `toml` or `json`.
+    EditOther,
+    /// Shell commands to run, like `cargo add bincode`.
+    Command,
+    /// Example or command output, only there to be read.
+    Output,
+}
+
+pub fn classify_block(language: &str, code: &str) -> BlockKind {
+    let language = language
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let has_prompt = code.lines().any(|line| prompt_command(line).is_some());
+    match language.as_str() {
+        // A script with a shebang is a file of its own.
+        _ if code.starts_with("#!") => BlockKind::Edit,
+        "sh" | "bash" | "zsh" | "fish" | "shell" | "powershell" | "pwsh" | "ps1" | "cmd" => {
+            BlockKind::Command
+        }
+        "console" | "shell-session" | "sh-session" | "terminal" if has_prompt => BlockKind::Command,
+        "console" | "shell-session" | "sh-session" | "terminal" | "text" | "txt" | "plaintext"
+        | "plain" | "output" | "log" | "stdout" | "stderr" => BlockKind::Output,
+        "" if has_prompt
+            && code
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .all(|line| prompt_command(line).is_some()) =>
+        {
+            BlockKind::Command
+        }
+        "" => BlockKind::Edit,
+        _ if normalize_language(&language).is_none() => BlockKind::EditOther,
+        _ => BlockKind::Edit,
+    }
+}
+
+/// The command of a `$ cargo build` line.
+fn prompt_command(line: &str) -> Option<&str> {
+    line.trim_start().strip_prefix("$ ")
+}
+
+/// The commands of a shell block. If it shows prompts, only the prompted
+/// lines are commands and the rest is their output.
+fn shell_commands(code: &str) -> String {
+    if !code.lines().any(|line| prompt_command(line).is_some()) {
+        return code.to_owned();
+    }
+    let mut commands = String::new();
+    let mut continued = false;
+    for line in code.lines() {
+        let command = match prompt_command(line) {
+            Some(command) => command,
+            None if continued => line,
+            None => continue,
+        };
+        commands.push_str(command);
+        commands.push('\n');
+        continued = command.trim_end().ends_with('\\');
+    }
+    commands
+}
+
+/// A heading, or a top-level list item outside any heading's section, with
+/// the paragraphs, list items and code blocks that follow it up to the next
+/// one.
+#[derive(Clone, Debug, Default)]
+pub struct Step {
+    /// Indices into [`ParsedLlmOutput::instructions`].
+    pub instructions: Vec<usize>,
+    /// Indices into [`ParsedLlmOutput::code_changes`].
+    pub code_changes: Vec<usize>,
 }
 
 #[derive(Clone, Debug)]
 pub struct ParsedLlmOutput {
     pub instructions: Vec<Instruction>,
     pub code_changes: Vec<CodeChange>,
+    pub search_replaces: Vec<SearchReplace>,
+    pub diffs: Vec<FileDiff>,
+    /// Shell commands the patch asks to run, one block each.
+    pub commands: Vec<String>,
+    /// Code for files in languages aiply does not edit.
+    pub other_edits: Vec<CodeChange>,
+    /// Where the command, output and other-language blocks are in the parsed
+    /// text, fences included, so that they can be left out of the prompt.
+    pub non_edit_blocks: Vec<Range<usize>>,
+    /// The instructions in order, each with its code blocks. Tagged blocks
+    /// belong to no step.
+    pub steps: Vec<Step>,
+}
+
+/// Parses a `@@ -10,7 +10,8 @@ fn foo` line, which models also write as
+/// `@@ ... @@` or a bare `@@`.
+fn parse_hunk_header(line: &str) -> Hunk {
+    let rest = &line["@@".len()..];
+    let (ranges, header) = rest.split_once("@@").unwrap_or((rest, ""));
+    let old_start = ranges
+        .split_whitespace()
+        .find_map(|range| range.strip_prefix('-'))
+        .and_then(|range| range.split(',').next()?.parse().ok());
+    Hunk {
+        old_start,
+        headers: match header.trim() {
+            "" => Vec::new(),
+            header => vec![header.to_owned()],
+        },
+        lines: Vec::new(),
+    }
+}
+
+/// Splits a unified diff into files and hunks. Lines before any `@@` still
+/// form a hunk, since models sometimes leave the header out.
+fn parse_unified_diff(code: &str) -> Vec<FileDiff> {
+    let mut diffs: Vec<FileDiff> = Vec::new();
+    let mut lines = code.lines().peekable();
+    while let Some(line) = lines.next() {
+        let is_file_header =
+            line.starts_with("--- ") && lines.peek().is_some_and(|next| next.starts_with("+++ "));
+        if is_file_header {
+            let strip = |path: &str, prefix: &str| {
+                let path = path.trim();
+                path.strip_prefix(prefix).unwrap_or(path).to_owned()
+            };
+            let old = strip(&line["--- ".len()..], "a/");
+            let new = strip(&lines.next().unwrap()["+++ ".len()..], "b/");
+            let (operation, path) = if old == "/dev/null" {
+                (FileOperation::Add, new)
+            } else if new == "/dev/null" {
+                (FileOperation::Delete, old)
+            } else {
+                (FileOperation::Update, new)
+            };
+            diffs.push(FileDiff {
+                operation,
+                path: Some(path),
+                move_to: None,
+                hunks: Vec::new(),
+            });
+            continue;
+        }
+        if diffs.is_empty() {
+            if line.starts_with("diff ") || line.starts_with("index ") {
+                continue;
+            }
+            diffs.push(FileDiff {
+                operation: FileOperation::Update,
+                path: None,
+                move_to: None,
+                hunks: Vec::new(),
+            });
+        }
+        let hunks = &mut diffs.last_mut().unwrap().hunks;
+        if line.starts_with("@@") {
+            hunks.push(parse_hunk_header(line));
+        } else if let Some(hunk_line) = parse_hunk_line(line) {
+            push_hunk_line(hunks, hunk_line);
+        }
+    }
+    finish_diffs(diffs)
+}
+
+fn parse_hunk_line(line: &str) -> Option<HunkLine> {
+    Some(match line.chars().next() {
+        Some('+') => HunkLine::Added(line[1..].to_owned()),
+        Some('-') => HunkLine::Removed(line[1..].to_owned()),
+        Some(' ') => HunkLine::Context(line[1..].to_owned()),
+        Some('\\') => return None,
+        // Models drop the space of empty context lines.
+        _ => HunkLine::Context(line.to_owned()),
+    })
+}
+
+fn push_hunk_line(hunks: &mut Vec<Hunk>, line: HunkLine) {
+    if hunks.is_empty() {
+        hunks.push(Hunk {
+            old_start: None,
+            headers: Vec::new(),
+            lines: Vec::new(),
+        });
+    }
+    hunks.last_mut().unwrap().lines.push(line);
+}
+
+fn finish_diffs(mut diffs: Vec<FileDiff>) -> Vec<FileDiff> {
+    for hunk in diffs.iter_mut().flat_map(|diff| &mut diff.hunks) {
+        // Blank lines between hunks are not context.
+        while hunk.lines.last() == Some(&HunkLine::Context(String::new())) {
+            hunk.lines.pop();
+        }
+    }
+    diffs.retain(|diff| diff.operation == FileOperation::Delete || !diff.hunks.is_empty());
+    diffs
+}
+
+/// Whether `code` is a V4A patch as emitted by OpenAI models.
+fn is_v4a_patch(code: &str) -> bool {
+    code.lines().any(|line| {
+        let line = line.trim_end();
+        line == "*** Begin Patch" || line.starts_with("*** Update File:")
+    })
+}
+
+/// Parses an `*** Begin Patch` ... `*** End Patch` patch. Its `@@` lines name
+/// the enclosing items instead of giving line numbers, possibly several for
+/// nested items, and become the hunk headers.
+fn parse_v4a_patch(code: &str) -> Vec<FileDiff> {
+    let mut diffs: Vec<FileDiff> = Vec::new();
+    for line in code.lines() {
+        let trimmed = line.trim_end();
+        let file = [
+            ("*** Add File:", FileOperation::Add),
+            ("*** Delete File:", FileOperation::Delete),
+            ("*** Update File:", FileOperation::Update),
+        ]
+        .into_iter()
+        .find_map(|(prefix, operation)| Some((operation, trimmed.strip_prefix(prefix)?)));
+        if let Some((operation, path)) = file {
+            diffs.push(FileDiff {
+                operation,
+                path: Some(path.trim().to_owned()),
+                move_to: None,
+                hunks: Vec::new(),
+            });
+            continue;
+        }
+        if trimmed.starts_with("*** ") {
+            if let (Some(path), Some(diff)) =
+                (trimmed.strip_prefix("*** Move to:"), diffs.last_mut())
+            {
+                diff.move_to = Some(path.trim().to_owned());
+            }
+            // `*** Begin Patch`, `*** End Patch` and `*** End of File`.
+            continue;
+        }
+        let Some(diff) = diffs.last_mut() else {
+            continue;
+        };
+        if let Some(anchor) = trimmed.strip_prefix("@@") {
+            // Stacked `@@` lines narrow down the same hunk.
+            if !diff.hunks.last().is_some_and(|hunk| hunk.lines.is_empty()) {
+                diff.hunks.push(Hunk {
+                    old_start: None,
+                    headers: Vec::new(),
+                    lines: Vec::new(),
+                });
+            }
+            let anchor = anchor.trim();
+            if !anchor.is_empty() {
+                diff.hunks
+                    .last_mut()
+                    .unwrap()
+                    .headers
+                    .push(anchor.to_owned());
+            }
+        } else if let Some(hunk_line) = parse_hunk_line(line) {
+            push_hunk_line(&mut diff.hunks, hunk_line);
+        }
+    }
+    finish_diffs(diffs)
+}
+
+/// Maps offsets in a text with some lines taken out back to offsets in the
+/// text they were taken from.
+#[derive(Default)]
+struct OffsetMap(Vec<(usize, usize)>);
+
+impl OffsetMap {
+    /// Notes that a line at `from` in the original is at `to` now.
+    fn keep(&mut self, to: usize, from: usize) {
+        self.0.push((to, from));
+    }
+
+    fn original(&self, offset: usize) -> usize {
+        match self.0.partition_point(|&(to, _)| to <= offset) {
+            0 => offset,
+            index => {
+                let (to, from) = self.0[index - 1];
+                from + offset - to
+            }
+        }
+    }
+
+    /// The end is mapped from the last byte in the range, which may end a
+    /// line that was followed by lines taken out.
+    fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        let start = self.original(range.start);
+        if range.is_empty() {
+            return start..start;
+        }
+        start..self.original(range.end - 1) + 1
+    }
+}
+
+/// Takes the V4A patches written outside code blocks out of `output`, since
+/// markdown would mangle their `***` lines.
+fn extract_v4a_patches(output: &str) -> (String, OffsetMap, Vec<FileDiff>) {
+    let mut rest = String::new();
+    let mut offsets = OffsetMap::default();
+    let mut patch: Option<String> = None;
+    let mut diffs = Vec::new();
+    let mut in_fence = false;
+    let mut pos = 0;
+    for line in output.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+        match &mut patch {
+            Some(text) => {
+                text.push_str(line);
+                if trimmed == "*** End Patch" {
+                    diffs.extend(parse_v4a_patch(text));
+                    patch = None;
+                }
+            }
+            None if !in_fence && trimmed == "*** Begin Patch" => patch = Some(line.to_owned()),
+            None => {
+                offsets.keep(rest.len(), pos);
+                rest.push_str(line);
+            }
+        }
+        pos += line.len();
+    }
+    if let Some(text) = patch {
+        diffs.extend(parse_v4a_patch(&text));
+    }
+    (rest, offsets, diffs)
+}
+
+/// Takes the `<file path="...">` and `<edit symbol="...">` blocks out of
+/// `output`, as code changes carrying their attributes. A code fence inside a
+/// block is unwrapped.
+fn extract_tagged_blocks(output: &str) -> (String, OffsetMap, Vec<CodeChange>) {
+    static OPEN_TAG: OnceLock<Regex> = OnceLock::new();
+    static ATTRIBUTE: OnceLock<Regex> = OnceLock::new();
+    let open_tag = OPEN_TAG.get_or_init(|| Regex::new(r"^<(file|edit)((?:\s[^>]*)?)>$").unwrap());
+    let attribute =
+        ATTRIBUTE.get_or_init(|| Regex::new(r#"([\w-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap());
+
+    let mut rest = String::new();
+    let mut offsets = OffsetMap::default();
+    let mut blocks = Vec::new();
+    // The tag being read, the block so far and where its content starts.
+    let mut block: Option<(String, CodeChange, usize)> = None;
+    let mut in_fence = false;
+    let mut pos = 0;
+    for line in output.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let is_fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+        match &mut block {
+            Some((tag, _, _)) if trimmed == format!("</{tag}>") => {
+                let (_, change, start) = block.take().unwrap();
+                blocks.push(finish_tagged_block(change, start));
+            }
+            Some((_, change, _)) => change.code.push_str(line),
+            None => {
+                if let Some(captures) = open_tag.captures(trimmed).filter(|_| !in_fence) {
+                    let mut change = CodeChange::default();
+                    for attr in attribute.captures_iter(&captures[2]) {
+                        let value = attr.get(2).or(attr.get(3)).unwrap().as_str().to_owned();
+                        match &attr[1] {
+                            "path" | "file" => change.path = Some(value),
+                            "symbol" => change.target_symbol = Some(value),
+                            "language" | "lang" => change.language = value,
+                            _ => {}
+                        }
+                    }
+                    block = Some((captures[1].to_owned(), change, pos + line.len()));
+                } else {
+                    in_fence ^= is_fence;
+                    offsets.keep(rest.len(), pos);
+                    rest.push_str(line);
+                }
+            }
+        }
+        pos += line.len();
+    }
+    if let Some((_, change, start)) = block {
+        blocks.push(finish_tagged_block(change, start));
+    }
+    (rest, offsets, blocks)
+}
+
+/// Unwraps the code of a tagged block whose content starts at `start`.
+fn finish_tagged_block(mut change: CodeChange, start: usize) -> CodeChange {
+    let code = unwrap_fence(&change.code, &mut change.language);
+    // The unwrapped code is a part of the content.
+    let start = start + change.code.find(&code).unwrap_or_default();
+    change.source_range = Some(start..start + code.len());
+    change.code = code;
+    change
+}
+
+/// The code inside a fence wrapping all of `code`, taking the language from
+/// the fence if there is none yet.
+fn unwrap_fence(code: &str, language: &mut String) -> String {
+    let trimmed = code.trim();
+    let fence = if trimmed.starts_with("```") {
+        "```"
+    } else if trimmed.starts_with("~~~") {
+        "~~~"
+    } else {
+        return code.trim_start_matches('\n').to_owned();
+    };
+    let Some((info, body)) = trimmed.split_once('\n') else {
+        return code.to_owned();
+    };
+    let Some(body) = body.trim_end().strip_suffix(fence) else {
+        return code.to_owned();
+    };
+    if language.is_empty() {
+        *language = info.trim_start_matches(fence).trim().to_owned();
+    }
+    body.to_owned()
+}
+
+/// Whether `line` is a marker of at least five `ch` followed by `word`, e.g.
+/// `<<<<<<< SEARCH`.
+fn is_marker(line: &str, ch: char, word: &str) -> bool {
+    let rest = line.trim().trim_start_matches(ch);
+    line.trim().len() - rest.len() >= 5 && rest.trim() == word
+}
+
+/// Splits a code block into its SEARCH/REPLACE blocks. Lines outside the
+/// blocks, like a file name, are ignored.
+fn parse_search_replace(language: &str, code: &str) -> Vec<SearchReplace> {
+    let mut blocks = Vec::new();
+    let mut lines = code.lines();
+    while let Some(line) = lines.next() {
+        if !is_marker(line, '<', "SEARCH") {
+            continue;
+        }
+        let mut search = String::new();
+        let mut replace = String::new();
+        let mut in_replace = false;
+        for line in lines.by_ref() {
+            if !in_replace && is_marker(line, '=', "") {
+                in_replace = true;
+            } else if in_replace && is_marker(line, '>', "REPLACE") {
+                blocks.push(SearchReplace {
+                    language: language.to_owned(),
+                    search,
+                    replace,
+                });
+                break;
+            } else {
+                let text = if in_replace {
+                    &mut replace
+                } else {
+                    &mut search
+                };
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+    }
+    blocks
 }
 
 impl ParsedLlmOutput {
+    /// Sorts a code block by what it holds: SEARCH/REPLACE blocks, a patch,
+    /// shell commands, output to ignore, or code to apply with the model.
+    fn push_code_block(&mut self, change: CodeChange) -> BlockKind {
+        let code = &change.code;
+        let blocks = parse_search_replace(&change.language, code);
+        let is_diff = matches!(change.language.as_str(), "diff" | "patch")
+            || code.lines().any(|line| line.starts_with("@@"));
+        if !blocks.is_empty() {
+            self.search_replaces.extend(blocks);
+            BlockKind::Edit
+        } else if is_v4a_patch(code) || is_diff {
+            let mut diffs = if is_v4a_patch(code) {
+                parse_v4a_patch(code)
+            } else {
+                parse_unified_diff(code)
+            };
+            for diff in &mut diffs {
+                diff.path = diff.path.take().or_else(|| change.path.clone());
+            }
+            self.diffs.extend(diffs);
+            BlockKind::Edit
+        } else if change.path.is_some() {
+            self.code_changes.push(change);
+            BlockKind::Edit
+        } else {
+            let kind = classify_block(&change.language, code);
+            match kind {
+                BlockKind::Edit => self.code_changes.push(change),
+                BlockKind::EditOther => self.other_edits.push(change),
+                BlockKind::Command => self.commands.push(shell_commands(code)),
+                BlockKind::Output => {}
+            }
+            kind
+        }
+    }
+
+    /// Ends the current instruction, if there is one.
+    fn push_instruction(&mut self, instruction: &mut Instruction) {
+        let mut instruction = std::mem::take(instruction);
+        if instruction.text.trim().is_empty() {
+            return;
+        }
+        instruction.text = instruction.text.trim().to_string();
+        self.instructions.push(instruction);
+        let index = self.instructions.len() - 1;
+        self.current_step().instructions.push(index);
+    }
+
+    /// Keeps the code changes `keep` accepts, updating the steps' indices.
+    pub fn retain_code_changes(&mut self, mut keep: impl FnMut(&CodeChange) -> bool) {
+        let mut new_indices = Vec::new();
+        let mut kept = 0;
+        self.code_changes.retain(|change| {
+            let keep = keep(change);
+            new_indices.push(keep.then_some(kept));
+            kept += usize::from(keep);
+            keep
+        });
+        for step in &mut self.steps {
+            step.code_changes = step
+                .code_changes
+                .iter()
+                .filter_map(|&index| new_indices[index])
+                .collect();
+        }
+    }
+
+    fn current_step(&mut self) -> &mut Step {
+        if self.steps.is_empty() {
+            self.steps.push(Step::default());
+        }
+        self.steps.last_mut().unwrap()
+    }
+
+    /// Starts a step, unless the current one has nothing in it yet.
+    fn start_step(&mut self) {
+        if self
+            .steps
+            .last()
+            .is_none_or(|step| !step.instructions.is_empty() || !step.code_changes.is_empty())
+        {
+            self.steps.push(Step::default());
+        }
+    }
+
     pub fn parse(output: &str) -> ParsedLlmOutput {
-        let parser = MarkdownParser::new(output);
+        let (output, v4a_offsets, diffs) = extract_v4a_patches(output);
+        let (output, tagged_offsets, tagged) = extract_tagged_blocks(&output);
+        let original_range =
+            |range| v4a_offsets.original_range(tagged_offsets.original_range(range));
+        let parser = MarkdownParser::new(&output).into_offset_iter();
         let mut parsed_output = ParsedLlmOutput {
             instructions: Vec::new(),
             code_changes: Vec::new(),
+            search_replaces: Vec::new(),
+            diffs,
+            commands: Vec::new(),
+            other_edits: Vec::new(),
+            non_edit_blocks: Vec::new(),
+            steps: Vec::new(),
         };
-        let mut current_instruction = String::new();
+        let mut current_instruction = Instruction::default();
         let mut in_code_block = false;
-        let mut current_code_change = CodeChange {
-            language: String::new(),
-            code: String::new(),
-        };
+        let mut current_code_change = CodeChange::default();
+        let mut block_range = 0..0;
+        let mut list_depth = 0;
+        // Under a heading, list items are part of its step.
+        let mut under_heading = false;
 
-        for event in parser {
+        for (event, range) in parser {
             match event {
                 Event::Text(text) => {
                     if in_code_block {
                         current_code_change.code.push_str(&text);
+                        let range = original_range(range);
+                        let source_range = current_code_change
+                            .source_range
+                            .get_or_insert(range.clone());
+                        source_range.end = range.end;
                     } else {
-                        current_instruction.push_str(&text);
+                        current_instruction.text.push_str(&text);
                     }
                 }
-                Event::Code(code) => {
-                    if !in_code_block {
-                        current_instruction.push_str(&format!("`{}`", code));
-                    }
+                Event::Code(code) if !in_code_block => {
+                    current_instruction.text.push_str(&format!("`{}`", code));
+                    current_instruction.code_spans.push(code.to_string());
                 }
                 Event::Start(Tag::CodeBlock(lang)) => {
+                    parsed_output.push_instruction(&mut current_instruction);
                     in_code_block = true;
+                    block_range = original_range(range);
                     current_code_change.language = match lang {
                         CodeBlockKind::Indented => String::new(),
                         CodeBlockKind::Fenced(lang) => lang.to_string(),
@@ -54,41 +719,53 @@ impl ParsedLlmOutput {
                 }
                 Event::End(TagEnd::CodeBlock) => {
                     in_code_block = false;
-                    parsed_output.code_changes.push(current_code_change.clone());
-                    current_code_change = CodeChange {
-                        language: String::new(),
-                        code: String::new(),
-                    };
-                    if !current_instruction.is_empty() {
-                        parsed_output.instructions.push(Instruction {
-                            text: current_instruction.trim().to_string(),
-                        });
-                        current_instruction.clear();
+                    let count = parsed_output.code_changes.len();
+                    let kind =
+                        parsed_output.push_code_block(std::mem::take(&mut current_code_change));
+                    if parsed_output.code_changes.len() > count {
+                        parsed_output.current_step().code_changes.push(count);
                     }
-                }
-                Event::SoftBreak | Event::HardBreak => {
-                    if !in_code_block {
-                        current_instruction.push('\n');
+                    if matches!(
+                        kind,
+                        BlockKind::Command | BlockKind::Output | BlockKind::EditOther
+                    ) {
+                        parsed_output.non_edit_blocks.push(block_range.clone());
                     }
                 }
-                Event::End(TagEnd::Paragraph) => {
-                    if !current_instruction.is_empty() {
-                        parsed_output.instructions.push(Instruction {
-                            text: current_instruction.trim().to_string(),
-                        });
-                        current_instruction.clear();
+                Event::Start(Tag::List(_)) => {
+                    parsed_output.push_instruction(&mut current_instruction);
+                    list_depth += 1;
+                }
+                Event::End(TagEnd::List(_)) => list_depth -= 1,
+                // List items of a tight list have no paragraphs to end them.
+                Event::Start(Tag::Item) => {
+                    parsed_output.push_instruction(&mut current_instruction);
+                    if list_depth == 1 && !under_heading {
+                        parsed_output.start_step();
                     }
                 }
+                Event::Start(Tag::Heading { .. }) => {
+                    parsed_output.push_instruction(&mut current_instruction);
+                    parsed_output.start_step();
+                    under_heading = true;
+                }
+                Event::End(TagEnd::Item | TagEnd::Heading(_) | TagEnd::Paragraph) => {
+                    parsed_output.push_instruction(&mut current_instruction);
+                }
+                Event::SoftBreak | Event::HardBreak if !in_code_block => {
+                    current_instruction.text.push('\n');
+                }
                 _ => {}
             }
         }
+        parsed_output.push_instruction(&mut current_instruction);
 
-        if !current_instruction.is_empty() {
-            parsed_output.instructions.push(Instruction {
-                text: current_instruction.trim().to_string(),
-            });
+        for mut change in tagged {
+            change.source_range = change
+                .source_range
+                .map(|range| v4a_offsets.original_range(range));
+            parsed_output.push_code_block(change);
         }
-
         parsed_output
     }
 }
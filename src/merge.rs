@@ -0,0 +1,175 @@
+use std::ops::Range;
+
+use similar::{DiffOp, TextDiff};
+
+/// Result of a three-way merge.
+pub struct Merged {
+    pub text: String,
+    /// Number of regions both sides changed differently, each left in `text`
+    /// between conflict markers.
+    pub conflicts: usize,
+}
+
+/// A run of base lines one side replaced.
+struct Change {
+    base: Range<usize>,
+    side: Range<usize>,
+}
+
+/// Groups the line diff ops into changes, joining adjacent deletes and inserts.
+fn changes(ops: &[DiffOp]) -> Vec<Change> {
+    let mut changes: Vec<Change> = Vec::new();
+    for op in ops {
+        if let DiffOp::Equal { .. } = op {
+            continue;
+        }
+        let (base, side) = (op.old_range(), op.new_range());
+        match changes.last_mut() {
+            Some(last) if last.base.end == base.start && last.side.end == side.start => {
+                last.base.end = base.end;
+                last.side.end = side.end;
+            }
+            _ => changes.push(Change { base, side }),
+        }
+    }
+    changes
+}
+
+/// The text one side has in place of `base_lines[range]`, given its changes
+/// within the range.
+fn side_text(
+    base_lines: &[&str],
+    side_lines: &[&str],
+    changes: &[Change],
+    range: Range<usize>,
+) -> String {
+    let mut text = String::new();
+    let mut pos = range.start;
+    for change in changes {
+        text.extend(base_lines[pos..change.base.start].iter().copied());
+        text.extend(side_lines[change.side.clone()].iter().copied());
+        pos = change.base.end;
+    }
+    text.extend(base_lines[pos..range.end].iter().copied());
+    text
+}
+
+fn push_conflict_side(result: &mut String, text: &str) {
+    result.push_str(text);
+    if !text.is_empty() && !text.ends_with('\n') {
+        result.push('\n');
+    }
+}
+
+/// Merges the changes `ours` and `theirs` each made to `base`, line by line.
+/// Changes that overlap or touch are a conflict unless both sides made the
+/// same one.
+#[tracing::instrument(name = "merge", skip_all, fields(conflicts))]
+pub fn merge3(base: &str, ours: &str, theirs: &str) -> Merged {
+    let ours_diff = TextDiff::from_lines(base, ours);
+    let theirs_diff = TextDiff::from_lines(base, theirs);
+    let base_lines = ours_diff.old_slices();
+    let ours_lines = ours_diff.new_slices();
+    let theirs_lines = theirs_diff.new_slices();
+    let ours_changes = changes(ours_diff.ops());
+    let theirs_changes = changes(theirs_diff.ops());
+
+    let mut text = String::new();
+    let mut conflicts = 0;
+    let mut pos = 0;
+    let (mut i, mut j) = (0, 0);
+    while i < ours_changes.len() || j < theirs_changes.len() {
+        let start = match (ours_changes.get(i), theirs_changes.get(j)) {
+            (Some(o), Some(t)) => o.base.start.min(t.base.start),
+            (Some(o), None) => o.base.start,
+            (None, Some(t)) => t.base.start,
+            (None, None) => unreachable!(),
+        };
+        // Pull in changes from either side until the region stops growing.
+        let (first_ours, first_theirs) = (i, j);
+        let mut end = start;
+        loop {
+            if let Some(change) = ours_changes.get(i).filter(|c| c.base.start <= end) {
+                end = end.max(change.base.end);
+                i += 1;
+            } else if let Some(change) = theirs_changes.get(j).filter(|c| c.base.start <= end) {
+                end = end.max(change.base.end);
+                j += 1;
+            } else {
+                break;
+            }
+        }
+
+        text.extend(base_lines[pos..start].iter().copied());
+        let ours_text = side_text(
+            base_lines,
+            ours_lines,
+            &ours_changes[first_ours..i],
+            start..end,
+        );
+        let theirs_text = side_text(
+            base_lines,
+            theirs_lines,
+            &theirs_changes[first_theirs..j],
+            start..end,
+        );
+        if first_theirs == j || ours_text == theirs_text {
+            text.push_str(&ours_text);
+        } else if first_ours == i {
+            text.push_str(&theirs_text);
+        } else {
+            conflicts += 1;
+            text.push_str("<<<<<<< current\n");
+            push_conflict_side(&mut text, &ours_text);
+            text.push_str("=======\n");
+            push_conflict_side(&mut text, &theirs_text);
+            text.push_str(">>>>>>> edit\n");
+        }
+        pos = end;
+    }
+    text.extend(base_lines[pos..].iter().copied());
+    tracing::Span::current().record("conflicts", conflicts);
+    Merged { text, conflicts }
+}
+
+/// Brings `edited`, an edit of `base`, onto `current`, the file as it is now.
+/// If the file changed since `base` was read, both changes are merged.
+pub fn merge_into_current(base: &str, edited: &str, current: &str) -> Merged {
+    if current == base {
+        return Merged {
+            text: edited.to_owned(),
+            conflicts: 0,
+        };
+    }
+    merge3(base, current, edited)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge3() {
+        let base = "fn a() {}\n\nfn b() {}\n\nfn c() {}\n";
+        let ours = "fn a() { 1 }\n\nfn b() {}\n\nfn c() {}\n";
+        let theirs = "fn a() {}\n\nfn b() {}\n\nfn c() { 3 }\nfn d() {}\n";
+        let merged = merge3(base, ours, theirs);
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(
+            merged.text,
+            "fn a() { 1 }\n\nfn b() {}\n\nfn c() { 3 }\nfn d() {}\n"
+        );
+
+        let merged = merge3(base, ours, ours);
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(merged.text, ours);
+
+        let theirs = "fn a() { 2 }\n\nfn b() {}\n\nfn c() {}";
+        let merged = merge3(base, ours, theirs);
+        assert_eq!(merged.conflicts, 1);
+        assert_eq!(
+            merged.text,
+            "<<<<<<< current\nfn a() { 1 }\n=======\nfn a() { 2 }\n>>>>>>> edit\n\nfn b() {}\n\nfn c() {}"
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
+use similar::{DiffOp, TextDiff};
+
+use crate::{CodeParsingContext, Symbol};
+
+/// The parts of a file a patch touches, used to tell requested changes from
+/// noise.
+#[derive(Default)]
+pub struct PatchSpans<'a> {
+    /// Ranges of the symbols the patch mentions, in the original.
+    pub original: Vec<Range<usize>>,
+    /// Ranges of the same symbols in the edited document, which covers items
+    /// the patch adds.
+    pub edited: Vec<Range<usize>>,
+    /// Trimmed non-empty lines of the code blocks, which covers changes
+    /// outside any symbol such as imports.
+    pub lines: HashSet<&'a str>,
+}
+
+impl<'a> PatchSpans<'a> {
+    pub fn new(
+        ctx: &mut CodeParsingContext,
+        original: &str,
+        edited: &str,
+        code_blocks: &[&'a str],
+        patch_symbols: &[Symbol],
+    ) -> Self {
+        PatchSpans {
+            original: ctx.symbol_ranges(original, patch_symbols),
+            edited: ctx.symbol_ranges(edited, patch_symbols),
+            lines: code_blocks
+                .iter()
+                .flat_map(|code| code.lines())
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect(),
+        }
+    }
+
+    fn touches(&self, original: Range<usize>, edited: Range<usize>, added: &[&str]) -> bool {
+        let overlaps = |spans: &[Range<usize>], range: &Range<usize>| {
+            // Pure insertions have an empty range and count if inside a span.
+            spans
+                .iter()
+                .any(|s| s.start <= range.end && range.start <= s.end)
+        };
+        overlaps(&self.original, &original)
+            || overlaps(&self.edited, &edited)
+            || added.iter().any(|line| self.lines.contains(line.trim()))
+    }
+}
+
+impl CodeParsingContext {
+    /// Byte ranges in `code` of the symbols matching any of `symbols`.
+    pub fn symbol_ranges(&mut self, code: &str, symbols: &[Symbol]) -> Vec<Range<usize>> {
+        let symbols_with_range = self.extract_symbols_with_range(code);
+        self.process_symbols(symbols_with_range)
+            .into_iter()
+            .filter(|s| {
+                symbols
+                    .iter()
+                    .any(|symbol| self.symbols_match(&s.symbol, symbol))
+            })
+            .map(|s| s.range)
+            .collect()
+    }
+}
+
+fn without_whitespace(lines: &[&str]) -> String {
+    lines
+        .iter()
+        .flat_map(|line| line.chars())
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
+
+/// Byte offsets of the start of each of `lines`, and of their end.
+pub(crate) fn line_offsets(lines: &[&str]) -> Vec<usize> {
+    let mut offsets = vec![0];
+    for line in lines {
+        offsets.push(offsets.last().unwrap() + line.len());
+    }
+    offsets
+}
+
+/// The runs of changed lines of a line diff, as the ranges of lines they
+/// cover in the old and the new text.
+pub(crate) fn changed_hunks(
+    ops: &[DiffOp],
+) -> impl Iterator<Item = (Range<usize>, Range<usize>)> + '_ {
+    ops.split(|op| matches!(op, DiffOp::Equal { .. }))
+        .filter(|run| !run.is_empty())
+        .map(|run| {
+            let (first, last) = (&run[0], &run[run.len() - 1]);
+            (
+                first.old_range().start..last.old_range().end,
+                first.new_range().start..last.new_range().end,
+            )
+        })
+}
+
+/// Reverts the hunks of a line diff between `original` and `edited` that
+/// only change whitespace or, given `spans`, that the patch did not ask for.
+#[tracing::instrument(name = "suppress_noise", skip_all, fields(dropped_hunks))]
+pub fn suppress_noise(original: &str, edited: &str, spans: Option<&PatchSpans>) -> String {
+    let diff = TextDiff::from_lines(original, edited);
+    let old_lines = diff.old_slices();
+    let new_lines = diff.new_slices();
+    let old_offsets = line_offsets(old_lines);
+    let new_offsets = line_offsets(new_lines);
+
+    let mut result = String::new();
+    let mut dropped_hunks = 0;
+    // First old line not yet in the result.
+    let mut kept = 0;
+    for (old, new) in changed_hunks(diff.ops()) {
+        result.extend(old_lines[kept..old.start].iter().copied());
+        kept = old.end;
+        let removed = &old_lines[old.clone()];
+        let added = &new_lines[new.clone()];
+        let noise = without_whitespace(removed) == without_whitespace(added)
+            || spans.is_some_and(|spans| {
+                !spans.touches(
+                    old_offsets[old.start]..old_offsets[old.end],
+                    new_offsets[new.start]..new_offsets[new.end],
+                    added,
+                )
+            });
+        if noise {
+            dropped_hunks += 1;
+            result.extend(removed.iter().copied());
+        } else {
+            result.extend(added.iter().copied());
+        }
+    }
+    result.extend(old_lines[kept..].iter().copied());
+    tracing::Span::current().record("dropped_hunks", dropped_hunks);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suppress_noise() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let original = "\
+use std::fmt;
+
+fn foo() {
+    let x = 1;
+}
+
+fn bar() {
+    let y = \"a\";
+}
+";
+        let edited = "\
+use std::fmt;
+use std::io;
+
+fn foo() {
+  let x = 1;
+    let z = 2;
+}
+
+fn bar() {
+    let y = 'a';
+}
+";
+        let code_block = "use std::io;\n\nfn foo() {\n    let z = 2;\n}\n";
+        let symbols = ctx.parse_code_symbols("rust", code_block);
+        let spans = PatchSpans::new(&mut ctx, original, edited, &[code_block], &symbols);
+        assert_eq!(
+            suppress_noise(original, edited, Some(&spans)),
+            "\
+use std::fmt;
+use std::io;
+
+fn foo() {
+  let x = 1;
+    let z = 2;
+}
+
+fn bar() {
+    let y = \"a\";
+}
+"
+        );
+    }
+}
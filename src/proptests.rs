@@ -0,0 +1,210 @@
+use crate::{CodeParsingContext, CollapsedDocument};
+use proptest::prelude::*;
+use proptest::sample::Index;
+use std::cell::RefCell;
+
+thread_local! {
+    // Building a context compiles the queries, which is too slow to do per case.
+    static RUST: RefCell<CodeParsingContext> = RefCell::new(CodeParsingContext::new("rust"));
+    static TYPESCRIPT: RefCell<CodeParsingContext> =
+        RefCell::new(CodeParsingContext::new("typescript"));
+}
+
+// A small pool so that generated files contain repeated summaries, e.g. several
+// `pub fn new` in different impls.
+fn name() -> impl Strategy<Value = String> {
+    prop_oneof![
+        prop::sample::select(vec!["new", "get", "insert", "run", "len"]).prop_map(String::from),
+        "[a-z][a-z0-9]{0,6}".prop_map(|s| format!("x{s}")),
+    ]
+}
+
+fn type_name() -> impl Strategy<Value = String> {
+    name().prop_map(|s| {
+        let mut chars = s.chars();
+        let first = chars.next().unwrap().to_ascii_uppercase();
+        std::iter::once(first).chain(chars).collect()
+    })
+}
+
+fn body(indent: &'static str) -> impl Strategy<Value = String> {
+    prop::collection::vec(name(), 0..4).prop_map(move |names| {
+        names
+            .iter()
+            .map(|n| format!("{indent}    let {n} = {n}_value();\n"))
+            .collect()
+    })
+}
+
+fn rust_fn(indent: &'static str) -> impl Strategy<Value = String> {
+    (any::<bool>(), name(), body(indent)).prop_map(move |(public, name, body)| {
+        let vis = if public { "pub " } else { "" };
+        format!("{indent}{vis}fn {name}() {{\n{body}{indent}}}\n")
+    })
+}
+
+fn rust_item() -> impl Strategy<Value = String> {
+    prop_oneof![
+        rust_fn(""),
+        (type_name(), prop::collection::vec(name(), 0..3)).prop_map(|(name, fields)| {
+            let fields: String = fields.iter().map(|f| format!("    {f}: u32,\n")).collect();
+            format!("#[derive(Debug)]\npub struct {name} {{\n{fields}}}\n")
+        }),
+        (type_name(), prop::collection::vec(rust_fn("    "), 0..3))
+            .prop_map(|(name, fns)| format!("impl {name} {{\n{}}}\n", fns.join("\n"))),
+        (name(), prop::collection::vec(rust_fn("    "), 0..3))
+            .prop_map(|(name, fns)| format!("mod {name} {{\n{}}}\n", fns.join("\n"))),
+        type_name().prop_map(|name| format!("const {}: u32 = 1;\n", name.to_uppercase())),
+        name().prop_map(|name| format!("use crate::{name};\n")),
+    ]
+}
+
+fn ts_method() -> impl Strategy<Value = String> {
+    (name(), body("  ")).prop_map(|(name, body)| format!("  {name}(): void {{\n{body}  }}\n"))
+}
+
+fn ts_item() -> impl Strategy<Value = String> {
+    prop_oneof![
+        (name(), body(""))
+            .prop_map(|(name, body)| format!("function {name}(): void {{\n{body}}}\n")),
+        (type_name(), prop::collection::vec(ts_method(), 0..3))
+            .prop_map(|(name, methods)| format!("class {name} {{\n{}}}\n", methods.join("\n"))),
+        (type_name(), prop::collection::vec(name(), 0..3)).prop_map(|(name, fields)| {
+            let fields: String = fields.iter().map(|f| format!("  {f}: number;\n")).collect();
+            format!("export interface {name} {{\n{fields}}}\n")
+        }),
+        (type_name(), prop::collection::vec(type_name(), 1..3)).prop_map(
+            |(name, variants)| format!("enum {name} {{\n  {},\n}}\n", variants.join(",\n  "))
+        ),
+        name().prop_map(|name| format!("import {{ {name} }} from \"./{name}\";\n")),
+    ]
+}
+
+fn source(item: impl Strategy<Value = String>) -> impl Strategy<Value = String> {
+    prop::collection::vec(item, 0..8).prop_map(|items| items.join("\n"))
+}
+
+#[derive(Clone, Debug)]
+enum Perturbation {
+    Reindent(Index, usize),
+    Dedent(Index),
+    DeleteLine(Index),
+    DuplicateLine(Index),
+    SwapItems(Index, Index),
+}
+
+fn perturbation() -> impl Strategy<Value = Perturbation> {
+    prop_oneof![
+        (any::<Index>(), 1..8usize).prop_map(|(i, n)| Perturbation::Reindent(i, n)),
+        any::<Index>().prop_map(Perturbation::Dedent),
+        any::<Index>().prop_map(Perturbation::DeleteLine),
+        any::<Index>().prop_map(Perturbation::DuplicateLine),
+        (any::<Index>(), any::<Index>()).prop_map(|(a, b)| Perturbation::SwapItems(a, b)),
+    ]
+}
+
+fn perturb(collapsed: &str, perturbations: &[Perturbation]) -> String {
+    // Items are separated by blank lines, so swapping paragraphs reorders them.
+    let mut items: Vec<Vec<String>> = collapsed
+        .split("\n\n")
+        .map(|item| item.lines().map(String::from).collect())
+        .collect();
+    for perturbation in perturbations {
+        if let Perturbation::SwapItems(a, b) = perturbation {
+            let (a, b) = (a.index(items.len()), b.index(items.len()));
+            items.swap(a, b);
+            continue;
+        }
+        let mut lines: Vec<String> = items.concat();
+        if lines.is_empty() {
+            continue;
+        }
+        match perturbation {
+            Perturbation::Reindent(i, n) => {
+                let i = i.index(lines.len());
+                lines[i] = format!("{}{}", " ".repeat(*n), lines[i]);
+            }
+            Perturbation::Dedent(i) => {
+                let i = i.index(lines.len());
+                lines[i] = lines[i].trim_start().to_owned();
+            }
+            Perturbation::DeleteLine(i) => {
+                lines.remove(i.index(lines.len()));
+            }
+            Perturbation::DuplicateLine(i) => {
+                let i = i.index(lines.len());
+                lines.insert(i, lines[i].clone());
+            }
+            Perturbation::SwapItems(..) => unreachable!(),
+        }
+        items = vec![lines];
+    }
+    items
+        .iter()
+        .map(|item| item.join("\n"))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Every collapse whose marker line survived the perturbation must be expanded
+/// back to its original text. Markers shared by several collapses are skipped,
+/// as which of them a surviving line belongs to is ambiguous.
+fn check_markers_restored(doc: &CollapsedDocument, perturbed: &str, uncollapsed: &str) {
+    let lines: Vec<&str> = perturbed.lines().map(str::trim).collect();
+    for collapse in &doc.collapses {
+        let summary = doc.summary(collapse);
+        let shared = doc
+            .collapses
+            .iter()
+            .filter(|c| doc.summary(c) == summary)
+            .count()
+            > 1;
+        let marker = format!("{summary} ...");
+        if !shared && lines.contains(&marker.as_str()) {
+            let original = &doc.original_document[collapse.target.clone()];
+            assert!(
+                uncollapsed.contains(original),
+                "marker {marker:?} was not restored to {original:?}"
+            );
+        }
+    }
+}
+
+fn check_uncollapse(ctx: &mut CodeParsingContext, source: &str, perturbations: &[Perturbation]) {
+    let doc = ctx.collapse_unrelated_symbols(source, vec![]);
+    let collapsed = doc.collapsed_document();
+    assert_eq!(doc.uncollapse_document(&collapsed), source);
+
+    let perturbed = perturb(&collapsed, perturbations);
+    let uncollapsed = doc.uncollapse_document(&perturbed);
+    check_markers_restored(&doc, &perturbed, &uncollapsed);
+}
+
+proptest! {
+    #[test]
+    fn uncollapse_rust(
+        source in source(rust_item()),
+        perturbations in prop::collection::vec(perturbation(), 0..6),
+    ) {
+        RUST.with_borrow_mut(|ctx| check_uncollapse(ctx, &source, &perturbations));
+    }
+
+    #[test]
+    fn uncollapse_typescript(
+        source in source(ts_item()),
+        perturbations in prop::collection::vec(perturbation(), 0..6),
+    ) {
+        TYPESCRIPT.with_borrow_mut(|ctx| check_uncollapse(ctx, &source, &perturbations));
+    }
+
+    #[test]
+    fn uncollapse_arbitrary_text(
+        source in source(rust_item()),
+        response in "(.{0,40}(\\.\\.\\.)?\n){0,10}",
+    ) {
+        RUST.with_borrow_mut(|ctx| {
+            let doc = ctx.collapse_unrelated_symbols(&source, vec![]);
+            doc.uncollapse_document(&response);
+        });
+    }
+}
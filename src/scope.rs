@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::changes::Version;
+use crate::{CodeParsingContext, Symbol, SymbolWithRange};
+
+/// A symbol the edit modified although the patch never mentioned it, or said
+/// to leave it alone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutOfScopeChange {
+    pub symbol: Symbol,
+    pub original_range: Range<usize>,
+    pub edited_range: Range<usize>,
+}
+
+/// Pairs each symbol with its number of earlier namesakes, so that e.g. two
+/// `impl Foo` blocks are matched in order between versions.
+pub(crate) fn occurrences(symbols: &[SymbolWithRange]) -> Vec<(&Symbol, usize)> {
+    let mut seen = HashMap::new();
+    symbols
+        .iter()
+        .map(|s| {
+            let count = seen.entry(&s.symbol).or_insert(0);
+            *count += 1;
+            (&s.symbol, *count - 1)
+        })
+        .collect()
+}
+
+impl CodeParsingContext {
+    /// Compares both versions symbol by symbol and returns the modified
+    /// symbols `allowed` says nothing about. A symbol counts as modified when
+    /// its own text changed, not just that of a nested symbol. Only the
+    /// outermost such symbol is reported when nested ones changed too.
+    fn modified_symbols(
+        &mut self,
+        original: &str,
+        edited: &str,
+        allowed: impl Fn(&Self, &Symbol) -> bool,
+    ) -> Vec<OutOfScopeChange> {
+        let symbols = self.extract_symbols_with_range(original);
+        let original = Version {
+            code: original,
+            symbols: self.process_symbols(symbols),
+        };
+        let symbols = self.extract_symbols_with_range(edited);
+        let edited = Version {
+            code: edited,
+            symbols: self.process_symbols(symbols),
+        };
+
+        let original_by_key: HashMap<_, _> = occurrences(&original.symbols)
+            .into_iter()
+            .enumerate()
+            .map(|(index, key)| (key, index))
+            .collect();
+        let mut changes: Vec<OutOfScopeChange> = Vec::new();
+        for (index, key) in occurrences(&edited.symbols).into_iter().enumerate() {
+            let Some(&original_index) = original_by_key.get(&key) else {
+                continue;
+            };
+            let edited_symbol = &edited.symbols[index];
+            let inside_reported = changes.iter().any(|c| {
+                c.edited_range.start <= edited_symbol.range.start
+                    && edited_symbol.range.end <= c.edited_range.end
+            });
+            if allowed(self, &edited_symbol.symbol)
+                || inside_reported
+                || original.own_text(original_index) == edited.own_text(index)
+            {
+                continue;
+            }
+            changes.push(OutOfScopeChange {
+                symbol: edited_symbol.symbol.clone(),
+                original_range: original.symbols[original_index].range.clone(),
+                edited_range: edited_symbol.range.clone(),
+            });
+        }
+        changes
+    }
+
+    /// Compares both versions symbol by symbol and returns the modified
+    /// symbols outside `important_symbols`. Only the outermost such symbol is
+    /// reported when nested ones changed too.
+    pub fn out_of_scope_changes(
+        &mut self,
+        original: &str,
+        edited: &str,
+        important_symbols: &[Symbol],
+    ) -> Vec<OutOfScopeChange> {
+        self.modified_symbols(original, edited, |context, symbol| {
+            important_symbols
+                .iter()
+                .any(|important| context.symbols_match(symbol, important))
+        })
+    }
+
+    /// Returns the modified symbols whose path ends with one of
+    /// `excluded_symbols`, which the patch says to leave alone.
+    pub fn excluded_changes(
+        &mut self,
+        original: &str,
+        edited: &str,
+        excluded_symbols: &[Symbol],
+    ) -> Vec<OutOfScopeChange> {
+        self.modified_symbols(original, edited, |_, symbol| {
+            !excluded_symbols
+                .iter()
+                .any(|excluded| symbol.parts.ends_with(&excluded.parts))
+        })
+    }
+}
+
+/// Puts the original text of each changed symbol back into `edited`.
+pub fn revert_changes(original: &str, edited: &str, changes: &[OutOfScopeChange]) -> String {
+    let mut changes: Vec<&OutOfScopeChange> = changes.iter().collect();
+    changes.sort_by_key(|c| c.edited_range.start);
+    let mut result = String::new();
+    let mut last_end = 0;
+    for change in changes {
+        result.push_str(&edited[last_end..change.edited_range.start]);
+        result.push_str(&original[change.original_range.clone()]);
+        last_end = change.edited_range.end;
+    }
+    result.push_str(&edited[last_end..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_out_of_scope_changes() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let original = "\
+impl Foo {
+    fn keep() {}
+
+    fn touch() {}
+}
+
+fn other() {}
+";
+        let edited = "\
+impl Foo {
+    fn keep() { 1 }
+
+    fn touch() { 2 }
+}
+
+fn other() { 3 }
+";
+        let important = [Symbol {
+            parts: vec!["Foo".to_owned(), "touch".to_owned()],
+        }];
+        let changes = ctx.out_of_scope_changes(original, edited, &important);
+        assert_eq!(
+            changes.iter().map(|c| &c.symbol).collect::<Vec<_>>(),
+            vec![
+                &Symbol {
+                    parts: vec!["Foo".to_owned(), "keep".to_owned()]
+                },
+                &Symbol {
+                    parts: vec!["other".to_owned()]
+                },
+            ]
+        );
+        assert_eq!(
+            revert_changes(original, edited, &changes),
+            original.replace("fn touch() {}", "fn touch() { 2 }")
+        );
+    }
+
+    #[test]
+    fn test_out_of_scope_changes_bare_name() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let original = "impl Foo {\n    fn keep() {}\n\n    fn touch() {}\n}\n";
+        let edited = "impl Foo {\n    fn keep() {}\n\n    fn touch() { 2 }\n}\n";
+        // The `impl Foo` around `touch` only changed through its child.
+        let important = [Symbol {
+            parts: vec!["touch".to_owned()],
+        }];
+        assert_eq!(
+            ctx.out_of_scope_changes(original, edited, &important),
+            vec![]
+        );
+
+        let edited = "impl Foo {\n    fn keep() { 1 }\n\n    fn touch() { 2 }\n}\n";
+        let changes = ctx.out_of_scope_changes(original, edited, &important);
+        assert_eq!(
+            revert_changes(original, edited, &changes),
+            original.replace("fn touch() {}", "fn touch() { 2 }")
+        );
+    }
+
+    #[test]
+    fn test_excluded_changes() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let original = "impl Foo {\n    fn keep() {}\n\n    fn touch() {}\n}\n";
+        let edited = "impl Foo {\n    fn keep() { 1 }\n\n    fn touch() { 2 }\n}\n";
+        let excluded = [Symbol {
+            parts: vec!["keep".to_owned()],
+        }];
+        let changes = ctx.excluded_changes(original, edited, &excluded);
+        assert_eq!(
+            revert_changes(original, edited, &changes),
+            original.replace("fn touch() {}", "fn touch() { 2 }")
+        );
+    }
+}
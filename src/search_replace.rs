@@ -0,0 +1,303 @@
+use std::ops::Range;
+
+use anyhow::bail;
+use similar::TextDiff;
+
+use crate::lazy::split_lines;
+use crate::markdown_parser::SearchReplace;
+use crate::{CodeParsingContext, Symbol};
+
+/// How similar, from 0 to 1, a run of lines must be to a SEARCH text to be
+/// replaced when nothing matches up to whitespace.
+const FUZZY_THRESHOLD: f32 = 0.8;
+
+pub(crate) fn normalize_whitespace(line: &str) -> String {
+    line.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn indentation(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Line ranges of `lines` matching `search`, using the strictest comparison
+/// that finds any: exact up to trailing whitespace, up to any whitespace, or
+/// the most similar ones, ignoring whitespace, above [`FUZZY_THRESHOLD`].
+pub(crate) fn find_candidates(lines: &[&str], search: &[&str]) -> Vec<Range<usize>> {
+    if search.len() > lines.len() {
+        return Vec::new();
+    }
+    let windows = || (0..=lines.len() - search.len()).map(|start| start..start + search.len());
+    let matching = |eq: fn(&str, &str) -> bool| -> Vec<Range<usize>> {
+        windows()
+            .filter(|window| {
+                lines[window.clone()]
+                    .iter()
+                    .zip(search)
+                    .all(|(line, search)| eq(line, search))
+            })
+            .collect()
+    };
+
+    let exact = matching(|a, b| a.trim_end() == b.trim_end());
+    if !exact.is_empty() {
+        return exact;
+    }
+    let whitespace = matching(|a, b| normalize_whitespace(a) == normalize_whitespace(b));
+    if !whitespace.is_empty() {
+        return whitespace;
+    }
+
+    let normalize = |lines: &[&str]| {
+        lines
+            .iter()
+            .map(|line| normalize_whitespace(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let search_text = normalize(search);
+    let scored: Vec<(Range<usize>, f32)> = windows()
+        .map(|window| {
+            let text = normalize(&lines[window.clone()]);
+            let ratio = TextDiff::from_chars(text.as_str(), search_text.as_str()).ratio();
+            (window, ratio)
+        })
+        .collect();
+    let best = scored.iter().map(|(_, ratio)| *ratio).fold(0.0, f32::max);
+    if best < FUZZY_THRESHOLD {
+        return Vec::new();
+    }
+    scored
+        .into_iter()
+        .filter(|(_, ratio)| *ratio == best)
+        .map(|(window, _)| window)
+        .collect()
+}
+
+/// `replace` with the indentation the SEARCH text has replaced by the
+/// indentation of the lines it matched.
+pub(crate) fn reindent(replace: &str, search_line: &str, matched_line: &str) -> String {
+    let (from, to) = (indentation(search_line), indentation(matched_line));
+    if from == to {
+        return replace.to_owned();
+    }
+    replace
+        .split_inclusive('\n')
+        .map(|line| match line.strip_prefix(from) {
+            Some(rest) if !line.trim().is_empty() => format!("{to}{rest}"),
+            _ => line.to_owned(),
+        })
+        .collect()
+}
+
+/// Tells where the lines of a text edited one range of lines at a time were
+/// in the original.
+#[derive(Default)]
+pub(crate) struct LineMap {
+    /// The ranges replaced so far, in order: the line each starts at, its
+    /// number of lines before and after.
+    replaced: Vec<(usize, usize, usize)>,
+}
+
+impl LineMap {
+    /// Records that `window` of the current text was replaced by `lines`
+    /// lines.
+    pub(crate) fn replace(&mut self, window: &Range<usize>, lines: usize) {
+        self.replaced.push((window.start, window.len(), lines));
+    }
+
+    /// The lines of the original that `window` of the current text covers. A
+    /// window overlapping replaced lines covers all the lines they replaced.
+    pub(crate) fn original(&self, window: &Range<usize>) -> Range<usize> {
+        let (mut start, mut end) = (window.start, window.end);
+        for &(at, before, after) in self.replaced.iter().rev() {
+            if start >= at + after {
+                start = start - after + before;
+            } else if start > at {
+                start = at;
+            }
+            if end >= at + after {
+                end = end - after + before;
+            } else if end > at {
+                end = at + before;
+            }
+        }
+        start..end
+    }
+}
+
+/// The source with the SEARCH/REPLACE blocks applied.
+#[derive(Debug)]
+pub struct SearchReplaceApplication {
+    pub text: String,
+    /// The 0-based lines of the source each block replaced, empty where it
+    /// only added lines.
+    pub windows: Vec<Range<usize>>,
+}
+
+impl CodeParsingContext {
+    /// Keeps the candidates overlapping a symbol that matches one of `hints`,
+    /// to tell apart e.g. the same method in two `impl` blocks.
+    pub(crate) fn disambiguate(
+        &mut self,
+        code: &str,
+        line_offsets: &[usize],
+        candidates: &[Range<usize>],
+        hints: &[Symbol],
+    ) -> Vec<Range<usize>> {
+        let symbols = self.extract_symbols_with_range(code);
+        let symbols = self.process_symbols(symbols);
+        candidates
+            .iter()
+            .filter(|&lines| {
+                let bytes = line_offsets[lines.start]..line_offsets[lines.end];
+                symbols.iter().any(|s| {
+                    s.range.start < bytes.end
+                        && bytes.start < s.range.end
+                        && hints.iter().any(|hint| self.symbols_match(&s.symbol, hint))
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Applies the blocks in order without the edit model. Each SEARCH text
+    /// has to match one place; if it matches several, the symbols the patch
+    /// mentions (`hints`) pick among them.
+    #[tracing::instrument(name = "search_replace", skip_all, fields(blocks = blocks.len()))]
+    pub fn apply_search_replace(
+        &mut self,
+        source: &str,
+        blocks: &[SearchReplace],
+        hints: &[Symbol],
+    ) -> anyhow::Result<SearchReplaceApplication> {
+        let mut code = source.to_owned();
+        let mut line_map = LineMap::default();
+        let mut windows = Vec::new();
+        for (number, block) in blocks.iter().enumerate().map(|(i, b)| (i + 1, b)) {
+            let search: Vec<&str> = block.search.lines().collect();
+            if search.iter().all(|line| line.trim().is_empty()) {
+                // An empty SEARCH appends, e.g. a new item.
+                if !code.is_empty() && !code.ends_with('\n') {
+                    code.push('\n');
+                }
+                let end = code.lines().count();
+                windows.push(line_map.original(&(end..end)));
+                line_map.replace(&(end..end), block.replace.lines().count());
+                code.push_str(&block.replace);
+                continue;
+            }
+
+            let (lines, line_offsets) = split_lines(&code);
+            let mut candidates = find_candidates(&lines, &search);
+            if candidates.len() > 1 {
+                let hinted = self.disambiguate(&code, &line_offsets, &candidates, hints);
+                if !hinted.is_empty() {
+                    candidates = hinted;
+                }
+            }
+            let window = match candidates.as_slice() {
+                [window] => window.clone(),
+                [] => bail!("SEARCH block {number} matches nothing:\n{}", block.search),
+                _ => bail!(
+                    "SEARCH block {number} matches {} places:\n{}",
+                    candidates.len(),
+                    block.search
+                ),
+            };
+            tracing::debug!(
+                block = number,
+                line = window.start + 1,
+                "Matched SEARCH block"
+            );
+
+            let mut replace = reindent(&block.replace, search[0], lines[window.start]);
+            let matched = &code[line_offsets[window.start]..line_offsets[window.end]];
+            if !matched.ends_with('\n') {
+                replace.truncate(replace.trim_end_matches('\n').len());
+            }
+            windows.push(line_map.original(&window));
+            line_map.replace(&window, replace.lines().count());
+            code.replace_range(
+                line_offsets[window.start]..line_offsets[window.end],
+                &replace,
+            );
+        }
+        Ok(SearchReplaceApplication {
+            text: code,
+            windows,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(search: &str, replace: &str) -> SearchReplace {
+        SearchReplace {
+            language: "rust".to_owned(),
+            search: search.to_owned(),
+            replace: replace.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_apply_search_replace() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let source = "\
+use std::io;
+
+impl Foo {
+    fn insert(&mut self) {
+        self.count += 1;
+    }
+}
+
+impl Bar {
+    fn insert(&mut self) {
+        self.count += 1;
+    }
+}
+";
+        let blocks = [
+            block("use std::io;\n", ""),
+            // Indented differently and with a typo.
+            block(
+                "fn insert(&mut self) {\n    self.cont += 1;\n}\n",
+                "fn insert(&mut self) {\n    self.count += 2;\n}\n",
+            ),
+        ];
+        let hints = [Symbol {
+            parts: vec!["Bar".to_owned(), "insert".to_owned()],
+        }];
+        let applied = ctx.apply_search_replace(source, &blocks, &hints).unwrap();
+        assert_eq!(
+            applied.text,
+            "
+impl Foo {
+    fn insert(&mut self) {
+        self.count += 1;
+    }
+}
+
+impl Bar {
+    fn insert(&mut self) {
+        self.count += 2;
+    }
+}
+"
+        );
+        // The second window is where `Bar::insert` was before the first block
+        // removed a line.
+        assert_eq!(applied.windows, [0..1, 9..12]);
+
+        let error = ctx
+            .apply_search_replace(source, &blocks[1..], &[])
+            .unwrap_err();
+        assert!(error.to_string().contains("matches 2 places"), "{error}");
+        let error = ctx
+            .apply_search_replace(source, &[block("fn remove() {}\n", "")], &[])
+            .unwrap_err();
+        assert!(error.to_string().contains("matches nothing"), "{error}");
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+/// Hash of a file's content, used to detect changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentHash([u8; 32]);
+
+pub struct FsState {
+    files: BTreeMap<FilePath, FileMetadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FilePath(Arc<str>);
+
+pub struct FileMetadata {
+    content_hash: ContentHash,
+}
+
+impl FsState {
+    pub fn new() -> Self {
+        Self {
+            files: BTreeMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, path: FilePath, metadata: FileMetadata) {
+        self.files.insert(path, metadata);
+    }
+
+    pub fn remove(&mut self, path: &FilePath) -> Option<FileMetadata> {
+        self.files.remove(path)
+    }
+}
+
+impl FilePath {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn hash_bytes(bytes: &[u8]) -> ContentHash {
+    let mut out = [0; 32];
+    for (i, b) in bytes.iter().enumerate() {
+        out[i % 32] ^= b;
+    }
+    ContentHash(out)
+}
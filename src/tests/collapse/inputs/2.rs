@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub trait Evict {
+    fn evict(&mut self) -> usize;
+}
+
+pub struct Cache<K, V> {
+    entries: HashMap<K, (V, u64)>,
+    capacity: usize,
+    clock: u64,
+}
+
+impl<K: Hash + Eq + Clone, V> Cache<K, V> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity,
+            clock: 0,
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.clock += 1;
+        let clock = self.clock;
+        self.entries.get_mut(key).map(|(value, used)| {
+            *used = clock;
+            &*value
+        })
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Evict for Cache<K, V> {
+    fn evict(&mut self) -> usize {
+        let mut evicted = 0;
+        while self.entries.len() > self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(key) = oldest {
+                self.entries.remove(&key);
+                evicted += 1;
+            }
+        }
+        evicted
+    }
+}
+
+mod stats {
+    pub const MAX_SAMPLES: usize = 1024;
+
+    pub fn mean(samples: &[u64]) -> f64 {
+        samples.iter().sum::<u64>() as f64 / samples.len() as f64
+    }
+}
@@ -0,0 +1,40 @@
+import { readFile } from "fs/promises";
+import { join } from "path";
+
+export interface Config {
+  root: string;
+  verbose: boolean;
+}
+
+export type Loader = (path: string) => Promise<string>;
+
+// Loads files relative to a fixed root directory.
+class FileLoader {
+  private cache: Map<string, string> = new Map();
+
+  constructor(private config: Config) {}
+
+  async load(path: string): Promise<string> {
+    const cached = this.cache.get(path);
+    if (cached !== undefined) {
+      return cached;
+    }
+    const content = await readFile(join(this.config.root, path), "utf8");
+    this.cache.set(path, content);
+    return content;
+  }
+
+  clear(): void {
+    this.cache.clear();
+  }
+}
+
+function createLoader(config: Config): Loader {
+  const loader = new FileLoader(config);
+  return (path) => loader.load(path);
+}
+
+export const DEFAULT_CONFIG: Config = {
+  root: ".",
+  verbose: false,
+};
@@ -0,0 +1,27 @@
+import { strict as assert } from "assert";
+
+enum Level {
+  Debug,
+  Info,
+  Warn,
+}
+
+namespace Format {
+  export function pad(value: number): string {
+    return value < 10 ? `0${value}` : `${value}`;
+  }
+
+  export function timestamp(date: Date): string {
+    return `${pad(date.getHours())}:${pad(date.getMinutes())}`;
+  }
+}
+
+function formatLine(level: Level, message: string): string {
+  return `[${Level[level]}] ${message}`;
+}
+
+describe("formatLine", () => {
+  it("prefixes the level", () => {
+    assert.equal(formatLine(Level.Info, "hi"), "[Info] hi");
+  });
+});
@@ -0,0 +1,216 @@
+use std::ops::Range;
+
+use crate::lazy::split_lines;
+use crate::markdown_parser::Hunk;
+use crate::search_replace::{find_candidates, reindent, LineMap};
+use crate::{CodeParsingContext, Symbol};
+
+/// The source with the hunks that could be placed applied.
+pub struct DiffApplication {
+    pub text: String,
+    /// Hunks whose context matches nowhere or in several places.
+    pub unplaced: Vec<Hunk>,
+    /// The 0-based lines of the source each placed hunk replaced, empty where
+    /// it only added lines.
+    pub windows: Vec<Range<usize>>,
+}
+
+impl CodeParsingContext {
+    /// Keeps the candidates inside the symbol the hunk headers name best, e.g.
+    /// `impl FsState {` or `fn foo(&self)`: the one with the most parts of its
+    /// path in the headers.
+    fn in_header_symbol(
+        &mut self,
+        code: &str,
+        line_offsets: &[usize],
+        candidates: &[Range<usize>],
+        headers: &[String],
+    ) -> Vec<Range<usize>> {
+        let words: Vec<&str> = headers
+            .iter()
+            .flat_map(|header| header.split(|c: char| !(c.is_alphanumeric() || c == '_')))
+            .filter(|word| !word.is_empty())
+            .collect();
+        let symbols = self.extract_symbols_with_range(code);
+        let regions: Vec<(Range<usize>, usize)> = self
+            .process_symbols(symbols)
+            .into_iter()
+            .filter(|s| words.contains(&s.symbol.parts.last().unwrap().as_str()))
+            .map(|s| {
+                let named = s
+                    .symbol
+                    .parts
+                    .iter()
+                    .filter(|part| words.contains(&part.as_str()))
+                    .count();
+                (s.range, named)
+            })
+            .collect();
+        let score = |lines: &Range<usize>| {
+            let start = line_offsets[lines.start];
+            regions
+                .iter()
+                .filter(|(r, _)| r.start <= start && start < r.end)
+                .map(|(_, named)| *named)
+                .max()
+                .unwrap_or(0)
+        };
+        let best = candidates.iter().map(score).max().unwrap_or(0);
+        if best == 0 {
+            return Vec::new();
+        }
+        candidates
+            .iter()
+            .filter(|&lines| score(lines) == best)
+            .cloned()
+            .collect()
+    }
+
+    /// Finds the lines `hunk` applies to by its context and removed lines.
+    /// Several matches are narrowed by the header symbol, then by `hints`,
+    /// then by the line number the hunk claims.
+    fn place_hunk(
+        &mut self,
+        code: &str,
+        lines: &[&str],
+        line_offsets: &[usize],
+        hunk: &Hunk,
+        old_start: Option<usize>,
+        hints: &[Symbol],
+    ) -> Option<Range<usize>> {
+        let old = hunk.text(false);
+        let search: Vec<&str> = old.lines().collect();
+        if search.iter().all(|line| line.trim().is_empty()) {
+            // Only additions: the line number is all there is to go on.
+            let at = old_start?.min(lines.len());
+            return Some(at..at);
+        }
+
+        let mut candidates = find_candidates(lines, &search);
+        if candidates.len() > 1 && !hunk.headers.is_empty() {
+            let narrowed = self.in_header_symbol(code, line_offsets, &candidates, &hunk.headers);
+            if !narrowed.is_empty() {
+                candidates = narrowed;
+            }
+        }
+        if candidates.len() > 1 {
+            let hinted = self.disambiguate(code, line_offsets, &candidates, hints);
+            if !hinted.is_empty() {
+                candidates = hinted;
+            }
+        }
+        if let (true, Some(start)) = (candidates.len() > 1, old_start) {
+            let distance = |c: &Range<usize>| (c.start + 1).abs_diff(start);
+            let nearest = candidates.iter().map(distance).min().unwrap();
+            candidates.retain(|c| distance(c) == nearest);
+        }
+        match candidates.as_slice() {
+            [window] => Some(window.clone()),
+            _ => None,
+        }
+    }
+
+    /// Applies the hunks in order, placing each by its context rather than
+    /// trusting its line numbers.
+    #[tracing::instrument(name = "apply_hunks", skip_all, fields(hunks = hunks.len(), unplaced))]
+    pub fn apply_hunks(
+        &mut self,
+        source: &str,
+        hunks: &[Hunk],
+        hints: &[Symbol],
+    ) -> DiffApplication {
+        let mut code = source.to_owned();
+        let mut unplaced = Vec::new();
+        let mut line_map = LineMap::default();
+        let mut windows = Vec::new();
+        // Lines added minus removed so far, to correct the claimed line numbers.
+        let mut shift = 0isize;
+        for hunk in hunks {
+            let (lines, line_offsets) = split_lines(&code);
+            let old_start = hunk
+                .old_start
+                .map(|start| start.saturating_add_signed(shift));
+            let Some(window) =
+                self.place_hunk(&code, &lines, &line_offsets, hunk, old_start, hints)
+            else {
+                unplaced.push(hunk.clone());
+                continue;
+            };
+            tracing::debug!(line = window.start + 1, "Placed hunk");
+
+            let (old, new) = (hunk.text(false), hunk.text(true));
+            let mut replace = match old.lines().find(|line| !line.trim().is_empty()) {
+                Some(first) if !window.is_empty() => reindent(&new, first, lines[window.start]),
+                _ => new.clone(),
+            };
+            let bytes = line_offsets[window.start]..line_offsets[window.end];
+            if !window.is_empty() && !code[bytes.clone()].ends_with('\n') {
+                replace.truncate(replace.trim_end_matches('\n').len());
+            }
+            shift += new.lines().count() as isize - window.len() as isize;
+            windows.push(line_map.original(&window));
+            line_map.replace(&window, new.lines().count());
+            code.replace_range(bytes, &replace);
+        }
+        tracing::Span::current().record("unplaced", unplaced.len());
+        DiffApplication {
+            text: code,
+            unplaced,
+            windows,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markdown_parser::ParsedLlmOutput;
+
+    #[test]
+    fn test_apply_hunks() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let source = "\
+impl Foo {
+    fn insert(&mut self) {
+        self.count += 1;
+    }
+}
+
+impl Bar {
+    fn insert(&mut self) {
+        self.count += 1;
+    }
+}
+";
+        let patch = "\
+```diff
+@@ -1,3 +1,3 @@ impl Bar {
+     fn insert(&mut self) {
+-        self.count += 1;
++        self.count += 2;
+     }
+@@ -2,3 +2,3 @@
+     fn insert(&mut self) {
+-        self.count += 1;
++        self.count += 3;
+     }
+@@ -20 @@
+-    fn remove(&mut self) {}
+```
+";
+        let parsed = ParsedLlmOutput::parse(patch);
+        let applied = ctx.apply_hunks(source, &parsed.diffs[0].hunks, &[]);
+        assert_eq!(
+            applied.text,
+            source
+                .replacen("+= 1", "+= 3", 1)
+                .replacen("+= 1", "+= 2", 1)
+        );
+        assert_eq!(applied.windows, [7..10, 1..4]);
+        assert_eq!(applied.unplaced.len(), 1);
+        assert_eq!(
+            applied.unplaced[0].to_string(),
+            "@@ -20 @@\n-    fn remove(&mut self) {}\n"
+        );
+    }
+}
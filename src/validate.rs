@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use tree_sitter::Node;
+
+use crate::{CodeParsingContext, Symbol};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SyntaxErrorKind {
+    /// Text the grammar could not parse.
+    Error,
+    /// A token the parser had to assume, e.g. a missing `}`.
+    Missing(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxError {
+    pub kind: SyntaxErrorKind,
+    /// 1-based line of the start of the error.
+    pub line: usize,
+    /// 1-based column, in bytes.
+    pub column: usize,
+    /// The offending line, for reporting.
+    pub source_line: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+    pub syntax_errors: Vec<SyntaxError>,
+    /// Top level symbols of the original that the edited document lost
+    /// although the patch never mentioned them.
+    pub missing_symbols: Vec<Symbol>,
+}
+
+impl ValidationReport {
+    pub fn is_ok(&self) -> bool {
+        self.syntax_errors.is_empty() && self.missing_symbols.is_empty()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for error in &self.syntax_errors {
+            match &error.kind {
+                SyntaxErrorKind::Error => {
+                    write!(f, "{}:{}: syntax error", error.line, error.column)?
+                }
+                SyntaxErrorKind::Missing(token) => {
+                    write!(f, "{}:{}: missing `{}`", error.line, error.column, token)?
+                }
+            }
+            writeln!(f, ": {}", error.source_line.trim())?;
+        }
+        for symbol in &self.missing_symbols {
+            writeln!(f, "unexpectedly removed: {symbol}")?;
+        }
+        Ok(())
+    }
+}
+
+fn collect_syntax_errors(node: Node, code: &str, errors: &mut Vec<SyntaxError>) {
+    if !node.has_error() {
+        return;
+    }
+    let kind = if node.is_error() {
+        SyntaxErrorKind::Error
+    } else if node.is_missing() {
+        SyntaxErrorKind::Missing(node.kind().to_owned())
+    } else {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            collect_syntax_errors(child, code, errors);
+        }
+        return;
+    };
+    let position = node.start_position();
+    errors.push(SyntaxError {
+        kind,
+        line: position.row + 1,
+        column: position.column + 1,
+        source_line: code
+            .lines()
+            .nth(position.row)
+            .unwrap_or_default()
+            .to_owned(),
+    });
+}
+
+impl CodeParsingContext {
+    /// Checks an edited document before it is written: it must not add syntax
+    /// errors to those of the original and must keep every top level symbol
+    /// of the original, except those the patch mentions.
+    #[tracing::instrument(name = "validate", skip_all)]
+    pub fn validate(
+        &mut self,
+        original: &str,
+        edited: &str,
+        important_symbols: &[Symbol],
+    ) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        let syntax_errors = |ctx: &mut Self, code: &str| {
+            let tree = ctx.grammar(ctx.language).parser.parse(code, None).unwrap();
+            let mut errors = Vec::new();
+            collect_syntax_errors(tree.root_node(), code, &mut errors);
+            errors
+        };
+        // Errors the original already has are matched by their line's text,
+        // since the edit may have moved them.
+        let mut existing: HashMap<(SyntaxErrorKind, String), usize> = HashMap::new();
+        for error in syntax_errors(self, original) {
+            *existing
+                .entry((error.kind, error.source_line.trim().to_owned()))
+                .or_default() += 1;
+        }
+        for error in syntax_errors(self, edited) {
+            let key = (error.kind.clone(), error.source_line.trim().to_owned());
+            match existing.get_mut(&key) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => report.syntax_errors.push(error),
+            }
+        }
+
+        let top_level = |ctx: &mut Self, code: &str| {
+            let symbols = ctx.extract_symbols_with_range(code);
+            ctx.process_symbols(symbols)
+                .into_iter()
+                .map(|s| s.symbol)
+                .filter(|s| s.parts.len() == 1)
+                .collect::<Vec<_>>()
+        };
+        let edited_symbols = top_level(self, edited);
+        for symbol in top_level(self, original) {
+            let mentioned = important_symbols
+                .iter()
+                .any(|important| self.symbols_match(&symbol, important));
+            if !mentioned
+                && !edited_symbols.contains(&symbol)
+                && !report.missing_symbols.contains(&symbol)
+            {
+                report.missing_symbols.push(symbol);
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let original = "struct Foo;\n\nfn bar() {}\n\nfn baz() {}\n";
+
+        let edited = "struct Foo;\n\nfn bar() {\n    let x = 1\n}\n\nfn baz() { ) }\n";
+        let report = ctx.validate(original, edited, &[]);
+        assert_eq!(
+            report
+                .syntax_errors
+                .iter()
+                .map(|e| (&e.kind, e.line, e.column))
+                .collect::<Vec<_>>(),
+            vec![
+                (&SyntaxErrorKind::Missing(";".to_owned()), 4, 14),
+                (&SyntaxErrorKind::Error, 7, 12)
+            ]
+        );
+        assert!(report.missing_symbols.is_empty());
+
+        let report = ctx.validate(original, "struct Foo;\n\nfn bar() {}\n", &[]);
+        assert_eq!(format!("{:?}", report.missing_symbols), "[#baz]");
+
+        let important = [Symbol {
+            parts: vec!["baz".to_owned()],
+        }];
+        let report = ctx.validate(original, "struct Foo;\n\nfn bar() {}\n", &important);
+        assert!(report.is_ok(), "{report}");
+    }
+
+    #[test]
+    fn test_validate_existing_errors() {
+        let mut ctx = CodeParsingContext::new("rust");
+        let original = "fn bar() { ) }\n\nfn baz() {}\n";
+        // The original's error moved down a line, only the new one counts.
+        let edited = "struct Foo;\nfn bar() { ) }\n\nfn baz() { let x = 1 }\n";
+        let report = ctx.validate(original, edited, &[]);
+        assert_eq!(
+            report
+                .syntax_errors
+                .iter()
+                .map(|e| (&e.kind, e.line))
+                .collect::<Vec<_>>(),
+            vec![(&SyntaxErrorKind::Missing(";".to_owned()), 4)]
+        );
+        assert!(ctx.validate(original, original, &[]).is_ok());
+    }
+}